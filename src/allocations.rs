@@ -0,0 +1,248 @@
+//! Submodule defining a global allocator wrapper counting the allocations of
+//! each thread, so that they can be attributed to the tasks running on it.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    ops::{Add, Sub},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Whether a [`CountingAllocator`] is installed and has served an allocation.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// The allocations counted on the current thread since it started.
+    static COUNTS: Cell<AllocationCounts> = const { Cell::new(AllocationCounts::ZERO) };
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+/// The number of allocations, reallocations and deallocations and the bytes
+/// they moved, as counted by a [`CountingAllocator`].
+///
+/// Read with [`AllocationCounts::current`], it holds the counts of the
+/// calling thread since it started; subtracting two readings gives the counts
+/// in between.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::allocations::AllocationCounts;
+///
+/// let counts = AllocationCounts {
+///     allocations: 3,
+///     reallocations: 1,
+///     deallocations: 2,
+///     allocated_bytes: 4096,
+///     deallocated_bytes: 1024,
+/// };
+/// assert_eq!(counts.net_bytes(), 3072);
+/// assert_eq!(counts - counts, AllocationCounts::default());
+/// ```
+pub struct AllocationCounts {
+    /// The number of allocations.
+    pub allocations: u64,
+    /// The number of reallocations, which grow or shrink an allocation.
+    pub reallocations: u64,
+    /// The number of deallocations.
+    pub deallocations: u64,
+    /// The number of bytes allocated, including by reallocations.
+    pub allocated_bytes: u64,
+    /// The number of bytes deallocated, including by reallocations.
+    pub deallocated_bytes: u64,
+}
+
+impl AllocationCounts {
+    /// The counts before any allocation.
+    const ZERO: Self = Self {
+        allocations: 0,
+        reallocations: 0,
+        deallocations: 0,
+        allocated_bytes: 0,
+        deallocated_bytes: 0,
+    };
+
+    #[must_use]
+    /// Returns the allocations counted so far on the calling thread, or
+    /// `None` if no [`CountingAllocator`] is installed as the global
+    /// allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::allocations::AllocationCounts;
+    ///
+    /// // The default global allocator does not count anything.
+    /// assert!(AllocationCounts::current().is_none());
+    /// ```
+    pub fn current() -> Option<Self> {
+        INSTALLED.load(Ordering::Relaxed).then(|| COUNTS.try_with(Cell::get).unwrap_or_default())
+    }
+
+    #[must_use]
+    /// Returns the number of bytes allocated and not deallocated, negative if
+    /// more memory was released than acquired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::allocations::AllocationCounts;
+    ///
+    /// let counts = AllocationCounts {
+    ///     deallocations: 1,
+    ///     deallocated_bytes: 512,
+    ///     ..AllocationCounts::default()
+    /// };
+    /// assert_eq!(counts.net_bytes(), -512);
+    /// ```
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn net_bytes(&self) -> i64 {
+        self.allocated_bytes as i64 - self.deallocated_bytes as i64
+    }
+}
+
+impl Add for AllocationCounts {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            allocations: self.allocations + other.allocations,
+            reallocations: self.reallocations + other.reallocations,
+            deallocations: self.deallocations + other.deallocations,
+            allocated_bytes: self.allocated_bytes + other.allocated_bytes,
+            deallocated_bytes: self.deallocated_bytes + other.deallocated_bytes,
+        }
+    }
+}
+
+impl Sub for AllocationCounts {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            allocations: self.allocations.saturating_sub(other.allocations),
+            reallocations: self.reallocations.saturating_sub(other.reallocations),
+            deallocations: self.deallocations.saturating_sub(other.deallocations),
+            allocated_bytes: self.allocated_bytes.saturating_sub(other.allocated_bytes),
+            deallocated_bytes: self.deallocated_bytes.saturating_sub(other.deallocated_bytes),
+        }
+    }
+}
+
+/// Updates the counts of the calling thread with the provided function, if
+/// they are still accessible.
+fn record(update: impl FnOnce(&mut AllocationCounts)) {
+    let _ = COUNTS.try_with(|counts| {
+        let mut current = counts.get();
+        update(&mut current);
+        counts.set(current);
+    });
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+/// A global allocator wrapping another one, [`System`] by default, and
+/// counting the allocations of each thread.
+///
+/// Once installed with `#[global_allocator]`, the tasks started with
+/// [`Task::with_allocations`](crate::task::Task::with_allocations) record the
+/// allocations performed on their thread while they run.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{allocations::CountingAllocator, prelude::*};
+///
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator::new(std::alloc::System);
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// let task = tracker.start_task("Collect").with_allocations();
+/// let names: Vec<String> = (0..100).map(|index| index.to_string()).collect();
+/// tracker.add_completed_task(task);
+///
+/// let allocations = tracker.get("Collect").unwrap().allocations().unwrap();
+/// assert!(allocations.allocations >= 100);
+/// assert!(allocations.net_bytes() > 0);
+/// drop(names);
+///
+/// let report = Report::from(tracker).with_options(ReportOptions::default().with_columns([
+///     Column::Name,
+///     Column::Allocations,
+///     Column::Deallocations,
+///     Column::AllocatedBytes,
+/// ]));
+/// assert!(report.to_string().contains("allocs"));
+/// ```
+pub struct CountingAllocator<A = System> {
+    /// The allocator serving the allocations.
+    inner: A,
+}
+
+impl<A> CountingAllocator<A> {
+    #[must_use]
+    /// Creates a new counting allocator wrapping the provided one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::allocations::CountingAllocator;
+    ///
+    /// static ALLOCATOR: CountingAllocator = CountingAllocator::new(std::alloc::System);
+    /// ```
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+// SAFETY: every call is forwarded unchanged to the wrapped allocator, and the
+// counters are kept in `const`-initialized thread-locals without destructors,
+// which never allocate.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        mark_installed();
+        record(|counts| {
+            counts.allocations += 1;
+            counts.allocated_bytes += layout.size() as u64;
+        });
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { self.inner.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        mark_installed();
+        record(|counts| {
+            counts.allocations += 1;
+            counts.allocated_bytes += layout.size() as u64;
+        });
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc_zeroed`.
+        unsafe { self.inner.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record(|counts| {
+            counts.deallocations += 1;
+            counts.deallocated_bytes += layout.size() as u64;
+        });
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { self.inner.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(|counts| {
+            counts.reallocations += 1;
+            counts.allocated_bytes += new_size as u64;
+            counts.deallocated_bytes += layout.size() as u64;
+        });
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::realloc`.
+        unsafe { self.inner.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Records that a counting allocator is installed, on its first allocation.
+fn mark_installed() {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+}
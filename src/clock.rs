@@ -0,0 +1,158 @@
+//! Submodule defining the clocks providing the current time to tasks and
+//! trackers, so that the time can be controlled in tests.
+
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Trait for the clocks providing the current time.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{clock::Clock, prelude::*};
+///
+/// /// A clock frozen at a fixed time.
+/// #[derive(Debug)]
+/// struct FrozenClock(chrono::NaiveDateTime);
+///
+/// impl Clock for FrozenClock {
+///     fn now(&self) -> chrono::NaiveDateTime {
+///         self.0
+///     }
+/// }
+///
+/// let start = chrono::NaiveDateTime::default();
+/// let mut tracker = TimeTracker::with_clock("Project", FrozenClock(start));
+/// tracker.add_completed_task("Task");
+/// assert_eq!(tracker.start(), start);
+/// assert_eq!(tracker.total_time(), chrono::TimeDelta::zero());
+/// ```
+pub trait Clock: std::fmt::Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> chrono::NaiveDateTime;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// The clock returning the local time of the system, used by default.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::clock::{Clock, SystemClock};
+///
+/// let before = chrono::Local::now().naive_local();
+/// assert!(SystemClock.now() >= before);
+/// ```
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> chrono::NaiveDateTime {
+        chrono::Local::now().naive_local()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// A clock which only moves when advanced manually, for deterministic tests.
+///
+/// Clones share the same time, so that a test can keep a clone to advance
+/// the clock while a tracker owns another one.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{clock::MockClock, prelude::*};
+///
+/// let clock = MockClock::default();
+/// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+///
+/// let task = tracker.start_task("Task");
+/// clock.advance(chrono::TimeDelta::seconds(2));
+/// tracker.add_completed_task(task);
+///
+/// assert_eq!(tracker.total_time(), chrono::TimeDelta::seconds(2));
+/// ```
+pub struct MockClock {
+    /// The current time, shared between the clones.
+    now: Arc<Mutex<chrono::NaiveDateTime>>,
+}
+
+impl MockClock {
+    #[must_use]
+    /// Creates a new mock clock starting at the provided time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::clock::{Clock, MockClock};
+    ///
+    /// let start = chrono::NaiveDate::from_ymd_opt(2025, 1, 1)
+    ///     .unwrap()
+    ///     .and_hms_opt(12, 0, 0)
+    ///     .unwrap();
+    /// assert_eq!(MockClock::new(start).now(), start);
+    /// ```
+    pub fn new(start: chrono::NaiveDateTime) -> Self {
+        Self { now: Arc::new(Mutex::new(start)) }
+    }
+
+    /// Advances the clock, and all its clones, by the provided duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::clock::{Clock, MockClock};
+    ///
+    /// let clock = MockClock::default();
+    /// let start = clock.now();
+    /// clock.advance(chrono::TimeDelta::milliseconds(250));
+    /// assert_eq!(clock.now() - start, chrono::TimeDelta::milliseconds(250));
+    /// ```
+    pub fn advance(&self, duration: chrono::TimeDelta) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) += duration;
+    }
+
+    /// Sets the clock, and all its clones, to the provided time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::clock::{Clock, MockClock};
+    ///
+    /// let clock = MockClock::default();
+    /// let time = chrono::NaiveDateTime::default() + chrono::TimeDelta::days(1);
+    /// clock.set(time);
+    /// assert_eq!(clock.now(), time);
+    /// ```
+    pub fn set(&self, time: chrono::NaiveDateTime) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) = time;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> chrono::NaiveDateTime {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A clock shared by a task with the tracker which started it, so that the
+/// task completes at the time of that clock. It is ignored when comparing and
+/// hashing tasks.
+pub(crate) struct SharedClock(pub(crate) Arc<dyn Clock>);
+
+impl Default for SharedClock {
+    fn default() -> Self {
+        Self(Arc::new(SystemClock))
+    }
+}
+
+impl PartialEq for SharedClock {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SharedClock {}
+
+impl std::hash::Hash for SharedClock {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
@@ -0,0 +1,224 @@
+//! Submodule defining the execution of external commands as tasks, recording
+//! their exit status and the resources used by the child process.
+
+use std::{
+    io::{self, Read},
+    process::{Child, Command, ExitStatus, Output, Stdio},
+};
+
+use crate::{
+    cpu::CpuTime, memory::MemoryUsage, outcome::Outcome, scheduling::SchedulingEvents, task::Task,
+    time_tracker::TimeTracker,
+};
+
+/// The resources used by a child process, as reported when it was reaped.
+#[derive(Debug, Clone, Copy, Default)]
+struct ChildUsage {
+    /// The CPU time consumed by the child.
+    cpu: Option<CpuTime>,
+    /// The peak resident set size of the child, in bytes.
+    peak_rss: Option<u64>,
+    /// The context switches and page faults of the child.
+    scheduling: Option<SchedulingEvents>,
+}
+
+impl ChildUsage {
+    /// Returns the resources used by a child process from its `rusage`.
+    #[cfg(target_os = "linux")]
+    fn from_rusage(usage: &libc::rusage) -> Self {
+        let count = |value: libc::c_long| u64::try_from(value).ok();
+        Self {
+            cpu: Some(CpuTime {
+                process_user: crate::cpu::timeval_to_delta(usage.ru_utime),
+                process_system: crate::cpu::timeval_to_delta(usage.ru_stime),
+                ..CpuTime::default()
+            }),
+            peak_rss: count(usage.ru_maxrss).map(|kilobytes| kilobytes * 1024),
+            scheduling: (|| {
+                Some(SchedulingEvents {
+                    voluntary_context_switches: count(usage.ru_nvcsw)?,
+                    involuntary_context_switches: count(usage.ru_nivcsw)?,
+                    minor_page_faults: count(usage.ru_minflt)?,
+                    major_page_faults: count(usage.ru_majflt)?,
+                })
+            })(),
+        }
+    }
+}
+
+/// Waits for the provided child to terminate, returning its exit status and,
+/// where it can be measured, the resources it used.
+fn wait(mut child: Child) -> io::Result<(ExitStatus, ChildUsage)> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        drop(child.stdin.take());
+        let pid = libc::pid_t::try_from(child.id()).map_err(io::Error::other)?;
+        let (status, usage) = crate::rusage::wait4(pid)?;
+        Ok((ExitStatus::from_raw(status), ChildUsage::from_rusage(&usage)))
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Ok((child.wait()?, ChildUsage::default()))
+    }
+}
+
+/// Reads the whole output of the provided pipe, if any.
+fn read_pipe(pipe: Option<impl Read>) -> io::Result<Vec<u8>> {
+    let mut output = Vec::new();
+    if let Some(mut pipe) = pipe {
+        pipe.read_to_end(&mut output)?;
+    }
+    Ok(output)
+}
+
+impl TimeTracker {
+    /// Runs the provided command to completion as a task with the provided
+    /// name, returning its exit status.
+    ///
+    /// The task fails if the command cannot be started or exits
+    /// unsuccessfully, and records the exit code as the `exit_code`
+    /// attribute. On Linux, the CPU time, peak resident set size, context
+    /// switches and page faults of the child process are read with `wait4`
+    /// and recorded as those of the task, leaving the resident set sizes at
+    /// the start and end of the task unknown. As with
+    /// [`add_completed_task`](Self::add_completed_task), a slash-separated
+    /// name records the task under the named sub-trackers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command cannot be started or waited for, in
+    /// which case the task is still recorded as failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::process::Command;
+    ///
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Build");
+    /// let status = tracker.run_command("tools/true", &mut Command::new("true")).unwrap();
+    /// assert!(status.success());
+    ///
+    /// let status = tracker.run_command("tools/false", &mut Command::new("false")).unwrap();
+    /// assert!(!status.success());
+    ///
+    /// let task = tracker.get("tools/false").unwrap();
+    /// assert!(task.outcome().is_failure());
+    /// assert_eq!(task.metadata().attribute("exit_code"), Some("1"));
+    /// if cfg!(target_os = "linux") {
+    ///     assert!(task.cpu_time().is_some());
+    ///     let memory = task.memory_usage().unwrap();
+    ///     assert!(memory.peak_rss > 0);
+    ///     assert_eq!(memory.start_rss, None);
+    /// }
+    ///
+    /// assert!(tracker.run_command("missing", &mut Command::new("no-such-tool")).is_err());
+    /// assert!(tracker.get("missing").unwrap().outcome().is_failure());
+    /// ```
+    pub fn run_command<S: ToString + ?Sized>(
+        &mut self,
+        name: &S,
+        command: &mut Command,
+    ) -> io::Result<ExitStatus> {
+        let task = self.start_task(name);
+        let result = command.spawn().and_then(wait);
+        self.record_command(task, result.as_ref().map(|&(status, usage)| (status, usage)), None);
+        result.map(|(status, _)| status)
+    }
+
+    /// Runs the provided command to completion as a task with the provided
+    /// name, capturing its standard output and error.
+    ///
+    /// The task is recorded as with [`run_command`](Self::run_command), and
+    /// additionally records the sizes of the captured outputs as the
+    /// `stdout_bytes` and `stderr_bytes` attributes.
+    ///
+    /// The standard output and error of the command are set to pipes,
+    /// replacing any previous configuration, and remain so afterwards: a
+    /// command reused with [`run_command`](Self::run_command) needs its
+    /// standard output and error set again to be shown.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command cannot be started, its outputs cannot
+    /// be read or it cannot be waited for, in which case the task is still
+    /// recorded as failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::process::Command;
+    ///
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Build");
+    /// let output = tracker
+    ///     .run_command_output("convert", Command::new("sh").args(["-c", "echo hello; echo oops >&2"]))
+    ///     .unwrap();
+    /// assert!(output.status.success());
+    /// assert_eq!(output.stdout, b"hello\n");
+    ///
+    /// let task = tracker.get("convert").unwrap();
+    /// assert_eq!(task.metadata().attribute("stdout_bytes"), Some("6"));
+    /// assert_eq!(task.metadata().attribute("stderr_bytes"), Some("5"));
+    /// assert_eq!(task.metadata().attribute("exit_code"), Some("0"));
+    /// ```
+    pub fn run_command_output<S: ToString + ?Sized>(
+        &mut self,
+        name: &S,
+        command: &mut Command,
+    ) -> io::Result<Output> {
+        let task = self.start_task(name);
+        let result =
+            command.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn().and_then(|mut child| {
+                let stderr = child.stderr.take();
+                let stderr = std::thread::spawn(move || read_pipe(stderr));
+                let stdout = read_pipe(child.stdout.take());
+                let stderr =
+                    stderr.join().map_err(|_| io::Error::other("stderr reader panicked"))?;
+                let (status, usage) = wait(child)?;
+                Ok((Output { status, stdout: stdout?, stderr: stderr? }, usage))
+            });
+        let sizes =
+            result.as_ref().ok().map(|(output, _)| (output.stdout.len(), output.stderr.len()));
+        self.record_command(
+            task,
+            result.as_ref().map(|(output, usage)| (output.status, *usage)),
+            sizes,
+        );
+        result.map(|(output, _)| output)
+    }
+
+    /// Records the task running a command, completed with the result of
+    /// waiting for the command and the sizes of its outputs, if captured.
+    fn record_command(
+        &mut self,
+        task: Task,
+        result: Result<(ExitStatus, ChildUsage), &io::Error>,
+        output_sizes: Option<(usize, usize)>,
+    ) {
+        let mut completed = task.complete();
+        match result {
+            Ok((status, usage)) => {
+                if !status.success() {
+                    completed.outcome = Outcome::failure(&status);
+                }
+                if let Some(code) = status.code() {
+                    completed.metadata.set_attribute("exit_code", &code);
+                }
+                completed.cpu = usage.cpu;
+                completed.memory = usage.peak_rss.map(MemoryUsage::from_peak);
+                completed.scheduling = usage.scheduling;
+            }
+            Err(error) => completed.outcome = Outcome::failure(error),
+        }
+        if let Some((stdout, stderr)) = output_sizes {
+            completed.metadata.set_attribute("stdout_bytes", &stdout);
+            completed.metadata.set_attribute("stderr_bytes", &stderr);
+        }
+        self.record(completed, false);
+    }
+}
@@ -0,0 +1,200 @@
+//! Submodule defining the CPU time consumed while a task runs, measured both
+//! for the whole process and for the calling thread.
+
+use std::ops::{Add, AddAssign, Sub};
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+/// The user and system CPU time consumed by the process and by the calling
+/// thread, serialized in nanoseconds.
+///
+/// Read with [`CpuTime::now`], it holds the CPU time consumed since the
+/// process or thread started; subtracting two readings gives the CPU time
+/// consumed in between.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeDelta;
+/// use time_requirements::cpu::CpuTime;
+///
+/// let cpu = CpuTime {
+///     process_user: TimeDelta::milliseconds(300),
+///     process_system: TimeDelta::milliseconds(100),
+///     thread_user: TimeDelta::milliseconds(200),
+///     thread_system: TimeDelta::milliseconds(50),
+/// };
+/// assert_eq!(cpu.process(), TimeDelta::milliseconds(400));
+/// assert_eq!(cpu.thread(), TimeDelta::milliseconds(250));
+///
+/// // A task waiting on I/O half of the time uses half of its wall time.
+/// assert_eq!(cpu.utilization(TimeDelta::milliseconds(800)), 50.0);
+/// ```
+pub struct CpuTime {
+    /// The CPU time spent by the process in user mode.
+    #[serde(with = "crate::nanoseconds")]
+    pub process_user: chrono::TimeDelta,
+    /// The CPU time spent by the process in kernel mode.
+    #[serde(with = "crate::nanoseconds")]
+    pub process_system: chrono::TimeDelta,
+    /// The CPU time spent by the calling thread in user mode.
+    #[serde(with = "crate::nanoseconds")]
+    pub thread_user: chrono::TimeDelta,
+    /// The CPU time spent by the calling thread in kernel mode.
+    #[serde(with = "crate::nanoseconds")]
+    pub thread_system: chrono::TimeDelta,
+}
+
+impl CpuTime {
+    #[must_use]
+    /// Returns the CPU time consumed so far by the process and by the calling
+    /// thread, or `None` on platforms where it cannot be measured.
+    ///
+    /// On Linux, the times are read with `getrusage`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::cpu::CpuTime;
+    ///
+    /// let Some(before) = CpuTime::now() else {
+    ///     assert!(!cfg!(target_os = "linux"));
+    ///     return;
+    /// };
+    /// let _sum: u64 = (0..1_000_000).map(std::hint::black_box).sum();
+    /// let consumed = CpuTime::now().unwrap() - before;
+    /// assert!(consumed.thread() > chrono::TimeDelta::zero());
+    /// assert!(consumed.process() > chrono::TimeDelta::zero());
+    /// ```
+    pub fn now() -> Option<Self> {
+        #[cfg(target_os = "linux")]
+        {
+            let process = crate::rusage::rusage(libc::RUSAGE_SELF)?;
+            let thread = crate::rusage::rusage(libc::RUSAGE_THREAD)?;
+            Some(Self {
+                process_user: timeval_to_delta(process.ru_utime),
+                process_system: timeval_to_delta(process.ru_stime),
+                thread_user: timeval_to_delta(thread.ru_utime),
+                thread_system: timeval_to_delta(thread.ru_stime),
+            })
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            None
+        }
+    }
+
+    #[must_use]
+    /// Returns the CPU time spent by the process in both user and kernel
+    /// mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use time_requirements::cpu::CpuTime;
+    ///
+    /// let cpu = CpuTime {
+    ///     process_user: TimeDelta::seconds(2),
+    ///     process_system: TimeDelta::seconds(1),
+    ///     ..CpuTime::default()
+    /// };
+    /// assert_eq!(cpu.process(), TimeDelta::seconds(3));
+    /// ```
+    pub fn process(&self) -> chrono::TimeDelta {
+        self.process_user + self.process_system
+    }
+
+    #[must_use]
+    /// Returns the CPU time spent by the calling thread in both user and
+    /// kernel mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use time_requirements::cpu::CpuTime;
+    ///
+    /// let cpu = CpuTime {
+    ///     thread_user: TimeDelta::seconds(2),
+    ///     thread_system: TimeDelta::seconds(1),
+    ///     ..CpuTime::default()
+    /// };
+    /// assert_eq!(cpu.thread(), TimeDelta::seconds(3));
+    /// ```
+    pub fn thread(&self) -> chrono::TimeDelta {
+        self.thread_user + self.thread_system
+    }
+
+    #[must_use]
+    /// Returns the CPU time of the process as a percentage of the provided
+    /// wall-clock time, or zero if the wall-clock time is zero.
+    ///
+    /// The utilization exceeds one hundred percent when several threads of
+    /// the process were busy at the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use time_requirements::cpu::CpuTime;
+    ///
+    /// let cpu = CpuTime { process_user: TimeDelta::seconds(4), ..CpuTime::default() };
+    /// assert_eq!(cpu.utilization(TimeDelta::seconds(2)), 200.0);
+    /// assert_eq!(cpu.utilization(TimeDelta::zero()), 0.0);
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    pub fn utilization(&self, wall_time: chrono::TimeDelta) -> f64 {
+        match (self.process().num_nanoseconds(), wall_time.num_nanoseconds()) {
+            (Some(cpu), Some(wall)) if wall != 0 => cpu as f64 / wall as f64 * 100.0,
+            _ => 0.0,
+        }
+    }
+}
+
+impl Add for CpuTime {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            process_user: self.process_user + other.process_user,
+            process_system: self.process_system + other.process_system,
+            thread_user: self.thread_user + other.thread_user,
+            thread_system: self.thread_system + other.thread_system,
+        }
+    }
+}
+
+impl AddAssign for CpuTime {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for CpuTime {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            process_user: self.process_user - other.process_user,
+            process_system: self.process_system - other.process_system,
+            thread_user: self.thread_user - other.thread_user,
+            thread_system: self.thread_system - other.thread_system,
+        }
+    }
+}
+
+impl std::iter::Sum for CpuTime {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// Converts the provided `timeval` into a `TimeDelta`.
+#[cfg(target_os = "linux")]
+#[allow(clippy::useless_conversion)]
+pub(crate) fn timeval_to_delta(time: libc::timeval) -> chrono::TimeDelta {
+    chrono::TimeDelta::seconds(i64::from(time.tv_sec))
+        + chrono::TimeDelta::microseconds(i64::from(time.tv_usec))
+}
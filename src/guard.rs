@@ -0,0 +1,194 @@
+//! Submodule defining scoped guards recording a task when they are dropped,
+//! including while the thread is unwinding from a panic.
+
+use crate::{
+    metadata::Metadata,
+    outcome::Outcome,
+    task::{IntoTask, Task},
+    time_tracker::TimeTracker,
+};
+
+/// A scoped guard over a running task, which records the task into its
+/// tracker when dropped.
+///
+/// If the guard is dropped while the thread is panicking, the task is
+/// recorded with the [`Outcome::Panicked`] outcome, so that the time spent
+/// before the crash is not lost. Otherwise, it is recorded with the outcome
+/// set through [`set_outcome`](Self::set_outcome), a success by default.
+///
+/// # Examples
+///
+/// ```
+/// use std::panic::{AssertUnwindSafe, catch_unwind};
+///
+/// use time_requirements::prelude::*;
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// {
+///     let _guard = tracker.guard("Setup");
+/// }
+///
+/// let result = catch_unwind(AssertUnwindSafe(|| {
+///     let _guard = tracker.guard("Crash");
+///     panic!("Out of memory");
+/// }));
+/// assert!(result.is_err());
+///
+/// // The report can still be written, showing which step died.
+/// assert!(tracker.get("Setup").unwrap().outcome().is_success());
+/// assert_eq!(tracker.get("Crash").unwrap().outcome().status(), "panicked");
+/// assert!(Report::from(tracker).to_string().contains("[panicked]"));
+/// ```
+pub struct TaskGuard<'a> {
+    /// The tracker recording the task.
+    tracker: &'a mut TimeTracker,
+    /// The running task, taken when it is recorded.
+    task: Option<Task>,
+    /// The outcome to record if the thread is not panicking.
+    outcome: Outcome,
+}
+
+impl TaskGuard<'_> {
+    /// Sets the outcome recorded when the guard is dropped, unless the thread
+    /// is panicking. The outcome may also be derived from a `Result`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{outcome::Outcome, prelude::*};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// {
+    ///     let mut guard = tracker.guard("Parse");
+    ///     let result = "forty-two".parse::<u32>();
+    ///     guard.set_outcome(&result);
+    /// }
+    /// assert!(tracker.get("Parse").unwrap().outcome().is_failure());
+    /// ```
+    pub fn set_outcome<O: Into<Outcome>>(&mut self, outcome: O) {
+        self.outcome = outcome.into();
+    }
+
+    /// Returns a mutable reference to the metadata of the running task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// {
+    ///     let mut guard = tracker.guard("Load");
+    ///     guard.metadata_mut().set_attribute("rows", &1000);
+    /// }
+    /// assert_eq!(tracker.get("Load").unwrap().metadata().attribute("rows"), Some("1000"));
+    /// ```
+    pub fn metadata_mut(&mut self) -> &mut Metadata {
+        self.task.as_mut().expect("the task is only taken when the guard is dropped").metadata_mut()
+    }
+
+    /// Adds the provided number of items to those processed by the running
+    /// task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// {
+    ///     let mut guard = tracker.guard("Annotate");
+    ///     for _spectrum in 0..3 {
+    ///         guard.add_items(1);
+    ///     }
+    /// }
+    /// assert_eq!(tracker.get("Annotate").unwrap().items(), Some(3));
+    /// ```
+    pub fn add_items(&mut self, items: u64) {
+        self.task
+            .as_mut()
+            .expect("the task is only taken when the guard is dropped")
+            .add_items(items);
+    }
+
+    /// Adds the provided number of bytes to those processed by the running
+    /// task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// {
+    ///     let mut guard = tracker.guard("Checksum");
+    ///     guard.add_bytes(4096);
+    /// }
+    /// assert_eq!(tracker.get("Checksum").unwrap().processed_bytes(), Some(4096));
+    /// ```
+    pub fn add_bytes(&mut self, bytes: u64) {
+        self.task
+            .as_mut()
+            .expect("the task is only taken when the guard is dropped")
+            .add_bytes(bytes);
+    }
+
+    /// Returns a mutable reference to the tracker recording the task, for
+    /// instance to guard nested tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// {
+    ///     let mut guard = tracker.guard("Build");
+    ///     let _nested = guard.tracker().guard("Compile");
+    /// }
+    /// assert_eq!(tracker.tasks().count(), 2);
+    /// ```
+    pub fn tracker(&mut self) -> &mut TimeTracker {
+        self.tracker
+    }
+}
+
+impl Drop for TaskGuard<'_> {
+    fn drop(&mut self) {
+        let Some(task) = self.task.take() else {
+            return;
+        };
+        let outcome = if std::thread::panicking() {
+            Outcome::Panicked { message: None }
+        } else {
+            std::mem::take(&mut self.outcome)
+        };
+        self.tracker.add_completed_task_with(task, outcome);
+    }
+}
+
+impl TimeTracker {
+    /// Starts the provided task and returns a guard recording it into the
+    /// tracker when dropped, with the [`Outcome::Panicked`] outcome if the
+    /// thread is panicking.
+    ///
+    /// As with [`add_completed_task`](Self::add_completed_task), a task whose
+    /// name is a slash-separated path is recorded under the sub-trackers
+    /// named by the leading components of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// {
+    ///     let _guard = tracker.guard("build/Compile");
+    /// }
+    /// assert!(tracker.get("build/Compile").is_some());
+    /// ```
+    pub fn guard<T: IntoTask>(&mut self, task: T) -> TaskGuard<'_> {
+        let task = task.into_task(self.shared_clock());
+        TaskGuard { tracker: self, task: Some(task), outcome: Outcome::Success }
+    }
+}
@@ -0,0 +1,409 @@
+//! Submodule defining the traversal, lookup and query API over the hierarchy
+//! of a tracker and its sub-trackers.
+//!
+//! Tasks are identified by their slash-separated path relative to the root
+//! tracker, such as `Sub Project/Task`, and by the depth of the tracker
+//! containing them, zero for the root.
+
+use std::collections::VecDeque;
+
+use crate::{task::CompletedTask, time_tracker::TimeTracker};
+
+/// The separator between the components of a path.
+pub const PATH_SEPARATOR: char = '/';
+
+/// Returns the path of a child named `name` of the provided parent path.
+fn join(parent: &str, name: &str) -> String {
+    if parent.is_empty() { name.to_owned() } else { format!("{parent}{PATH_SEPARATOR}{name}") }
+}
+
+/// Depth-first, pre-order iterator over the tasks of a hierarchy of
+/// trackers, yielding `(path, depth, task)`.
+///
+/// Tasks summarizing a sub-tracker are yielded right before the tasks of the
+/// sub-tracker.
+pub struct DepthFirst<'a> {
+    /// The stack of the trackers being visited, with their path, depth and
+    /// the index of their next task.
+    stack: Vec<(String, usize, &'a TimeTracker, usize)>,
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = (String, usize, &'a CompletedTask);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (prefix, depth, tracker, index) = self.stack.last_mut()?;
+            let Some((task, sub_tracker)) = tracker.entry(*index) else {
+                self.stack.pop();
+                continue;
+            };
+            *index += 1;
+
+            let (path, depth) = (join(prefix, task.name()), *depth);
+            if let Some(sub_tracker) = sub_tracker {
+                self.stack.push((path.clone(), depth + 1, sub_tracker, 0));
+            }
+            return Some((path, depth, task));
+        }
+    }
+}
+
+/// Breadth-first iterator over the tasks of a hierarchy of trackers,
+/// yielding `(path, depth, task)`.
+///
+/// All the tasks of a tracker are yielded before those of its sub-trackers.
+pub struct BreadthFirst<'a> {
+    /// The queue of the trackers to visit, with their path and depth.
+    queue: VecDeque<(String, usize, &'a TimeTracker)>,
+    /// The tracker being visited, with its path, depth and the index of its
+    /// next task.
+    current: Option<(String, usize, &'a TimeTracker, usize)>,
+}
+
+impl<'a> Iterator for BreadthFirst<'a> {
+    type Item = (String, usize, &'a CompletedTask);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.current.is_none() {
+                let (prefix, depth, tracker) = self.queue.pop_front()?;
+                self.current = Some((prefix, depth, tracker, 0));
+            }
+            let (prefix, depth, tracker, index) = self.current.as_mut()?;
+            let Some((task, sub_tracker)) = tracker.entry(*index) else {
+                self.current = None;
+                continue;
+            };
+            *index += 1;
+
+            let path = join(prefix, task.name());
+            if let Some(sub_tracker) = sub_tracker {
+                self.queue.push_back((path.clone(), *depth + 1, sub_tracker));
+            }
+            return Some((path, *depth, task));
+        }
+    }
+}
+
+/// Trait for visitors walking a hierarchy of trackers with
+/// [`TimeTracker::accept`].
+///
+/// All methods default to doing nothing, so that visitors only implement
+/// the ones they need.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{
+///     hierarchy::Visitor,
+///     prelude::*,
+///     task::CompletedTask,
+/// };
+///
+/// /// Collects the names of the trackers and the number of tasks.
+/// #[derive(Default)]
+/// struct Counter {
+///     trackers: Vec<String>,
+///     tasks: usize,
+/// }
+///
+/// impl Visitor for Counter {
+///     fn enter_tracker(&mut self, _path: &str, _depth: usize, tracker: &TimeTracker) {
+///         self.trackers.push(tracker.name().to_owned());
+///     }
+///
+///     fn visit_task(&mut self, _path: &str, _depth: usize, _task: &CompletedTask) {
+///         self.tasks += 1;
+///     }
+/// }
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// tracker.add_completed_task(Task::new("Task"));
+/// let mut sub_tracker = TimeTracker::new("Sub Project");
+/// sub_tracker.add_completed_task(Task::new("Sub Task"));
+/// tracker.extend(sub_tracker);
+///
+/// let mut counter = Counter::default();
+/// tracker.accept(&mut counter);
+/// assert_eq!(counter.trackers, ["Project", "Sub Project"]);
+/// assert_eq!(counter.tasks, 3);
+/// ```
+pub trait Visitor {
+    /// Called when entering a tracker, before visiting its tasks.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the tracker, empty for the root.
+    /// * `depth` - The depth of the tracker, zero for the root.
+    /// * `tracker` - The tracker.
+    fn enter_tracker(&mut self, _path: &str, _depth: usize, _tracker: &TimeTracker) {}
+
+    /// Called for each task, before entering the sub-tracker it summarizes,
+    /// if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the task.
+    /// * `depth` - The depth of the tracker containing the task.
+    /// * `task` - The task.
+    fn visit_task(&mut self, _path: &str, _depth: usize, _task: &CompletedTask) {}
+
+    /// Called when leaving a tracker, after visiting its tasks.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the tracker, empty for the root.
+    /// * `depth` - The depth of the tracker, zero for the root.
+    /// * `tracker` - The tracker.
+    fn leave_tracker(&mut self, _path: &str, _depth: usize, _tracker: &TimeTracker) {}
+}
+
+/// Returns whether the provided name matches the provided glob pattern, where
+/// `*` matches any sequence of characters and `?` matches any single
+/// character.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::hierarchy::matches_glob;
+///
+/// assert!(matches_glob("parse*", "parse spectra"));
+/// assert!(matches_glob("*spectra", "parse spectra"));
+/// assert!(matches_glob("load ?", "load 1"));
+/// assert!(!matches_glob("load ?", "load 10"));
+/// assert!(matches_glob("*", ""));
+/// ```
+#[must_use]
+pub fn matches_glob(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut pattern_index, mut name_index) = (0, 0);
+    // Position of the last star in the pattern, and of the name character it
+    // was matched up to, to backtrack on mismatches.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while name_index < name.len() {
+        match pattern.get(pattern_index) {
+            Some('*') => {
+                backtrack = Some((pattern_index, name_index));
+                pattern_index += 1;
+            }
+            Some(&character) if character == '?' || character == name[name_index] => {
+                pattern_index += 1;
+                name_index += 1;
+            }
+            _ => {
+                let Some((star_index, star_name_index)) = backtrack else {
+                    return false;
+                };
+                backtrack = Some((star_index, star_name_index + 1));
+                pattern_index = star_index + 1;
+                name_index = star_name_index + 1;
+            }
+        }
+    }
+
+    pattern[pattern_index..].iter().all(|&character| character == '*')
+}
+
+impl TimeTracker {
+    /// Returns a depth-first iterator over the tasks of the tracker and of
+    /// its sub-trackers, yielding `(path, depth, task)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut sub_tracker = TimeTracker::new("Sub Project");
+    /// sub_tracker.add_completed_task(Task::new("Sub Task"));
+    /// tracker.extend(sub_tracker);
+    /// tracker.add_completed_task(Task::new("Task"));
+    ///
+    /// let paths: Vec<(String, usize)> =
+    ///     tracker.depth_first().map(|(path, depth, _)| (path, depth)).collect();
+    /// assert_eq!(
+    ///     paths,
+    ///     [
+    ///         ("Sub Project".to_owned(), 0),
+    ///         ("Sub Project/Sub Task".to_owned(), 1),
+    ///         ("Task".to_owned(), 0),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn depth_first(&self) -> DepthFirst<'_> {
+        DepthFirst { stack: vec![(String::new(), 0, self, 0)] }
+    }
+
+    /// Returns a breadth-first iterator over the tasks of the tracker and of
+    /// its sub-trackers, yielding `(path, depth, task)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut sub_tracker = TimeTracker::new("Sub Project");
+    /// sub_tracker.add_completed_task(Task::new("Sub Task"));
+    /// tracker.extend(sub_tracker);
+    /// tracker.add_completed_task(Task::new("Task"));
+    ///
+    /// let paths: Vec<String> = tracker.breadth_first().map(|(path, _, _)| path).collect();
+    /// assert_eq!(paths, ["Sub Project", "Task", "Sub Project/Sub Task"]);
+    /// ```
+    #[must_use]
+    pub fn breadth_first(&self) -> BreadthFirst<'_> {
+        BreadthFirst { queue: VecDeque::from([(String::new(), 0, self)]), current: None }
+    }
+
+    /// Returns the sub-tracker at the provided slash-separated path, where
+    /// the empty path is the tracker itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut sub_tracker = TimeTracker::new("Sub Project");
+    /// sub_tracker.extend(TimeTracker::new("Nested Project"));
+    /// tracker.extend(sub_tracker);
+    ///
+    /// assert_eq!(tracker.get_tracker("").unwrap().name(), "Project");
+    /// assert_eq!(
+    ///     tracker.get_tracker("Sub Project/Nested Project").unwrap().name(),
+    ///     "Nested Project"
+    /// );
+    /// assert!(tracker.get_tracker("Missing").is_none());
+    /// ```
+    #[must_use]
+    pub fn get_tracker(&self, path: &str) -> Option<&TimeTracker> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        path.split(PATH_SEPARATOR).try_fold(self, |tracker, name| {
+            tracker.sub_trackers().find(|sub_tracker| sub_tracker.name() == name)
+        })
+    }
+
+    /// Returns the task at the provided slash-separated path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// let mut sub_tracker = TimeTracker::new("Sub Project");
+    /// sub_tracker.add_completed_task(Task::new("Sub Task"));
+    /// tracker.extend(sub_tracker);
+    ///
+    /// assert_eq!(tracker.get("Task").unwrap().name(), "Task");
+    /// assert_eq!(tracker.get("Sub Project").unwrap().name(), "Sub Project");
+    /// assert_eq!(tracker.get("Sub Project/Sub Task").unwrap().name(), "Sub Task");
+    /// assert!(tracker.get("Sub Project/Missing").is_none());
+    /// ```
+    #[must_use]
+    pub fn get(&self, path: &str) -> Option<&CompletedTask> {
+        let (parent, name) = path.rsplit_once(PATH_SEPARATOR).unwrap_or(("", path));
+        self.get_tracker(parent)?.tasks().find(|task| task.name() == name)
+    }
+
+    /// Returns an iterator over the tasks of the hierarchy, in depth-first
+    /// order, whose name matches the provided glob pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("parse spectra"));
+    /// let mut sub_tracker = TimeTracker::new("Sub Project");
+    /// sub_tracker.add_completed_task(Task::new("parse metadata"));
+    /// sub_tracker.add_completed_task(Task::new("write"));
+    /// tracker.extend(sub_tracker);
+    ///
+    /// let paths: Vec<String> = tracker.filter_glob("parse *").map(|(path, _, _)| path).collect();
+    /// assert_eq!(paths, ["parse spectra", "Sub Project/parse metadata"]);
+    /// ```
+    pub fn filter_glob<'a>(
+        &'a self,
+        pattern: &'a str,
+    ) -> impl Iterator<Item = (String, usize, &'a CompletedTask)> + 'a {
+        self.depth_first().filter(move |(_, _, task)| matches_glob(pattern, task.name()))
+    }
+
+    #[cfg(feature = "regex")]
+    /// Returns an iterator over the tasks of the hierarchy, in depth-first
+    /// order, whose name matches the provided regular expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use regex::Regex;
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("load batch 1"));
+    /// tracker.add_completed_task(Task::new("load batch 2"));
+    /// tracker.add_completed_task(Task::new("write"));
+    ///
+    /// let regex = Regex::new(r"^load batch \d+$").unwrap();
+    /// assert_eq!(tracker.filter_regex(&regex).count(), 2);
+    /// ```
+    pub fn filter_regex<'a>(
+        &'a self,
+        regex: &'a regex::Regex,
+    ) -> impl Iterator<Item = (String, usize, &'a CompletedTask)> + 'a {
+        self.depth_first().filter(move |(_, _, task)| regex.is_match(task.name()))
+    }
+
+    /// Walks the hierarchy depth-first with the provided visitor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{hierarchy::Visitor, prelude::*, task::CompletedTask};
+    ///
+    /// /// Collects the paths of the tasks.
+    /// struct Paths(Vec<String>);
+    ///
+    /// impl Visitor for Paths {
+    ///     fn visit_task(&mut self, path: &str, _depth: usize, _task: &CompletedTask) {
+    ///         self.0.push(path.to_owned());
+    ///     }
+    /// }
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut sub_tracker = TimeTracker::new("Sub Project");
+    /// sub_tracker.add_completed_task(Task::new("Sub Task"));
+    /// tracker.extend(sub_tracker);
+    ///
+    /// let mut paths = Paths(Vec::new());
+    /// tracker.accept(&mut paths);
+    /// assert_eq!(paths.0, ["Sub Project", "Sub Project/Sub Task"]);
+    /// ```
+    pub fn accept<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.accept_at(visitor, "", 0);
+    }
+
+    /// Walks the tracker, located at the provided path and depth, with the
+    /// provided visitor.
+    fn accept_at<V: Visitor + ?Sized>(&self, visitor: &mut V, path: &str, depth: usize) {
+        visitor.enter_tracker(path, depth, self);
+        for task in self.tasks() {
+            let task_path = join(path, task.name());
+            visitor.visit_task(&task_path, depth, task);
+            if let Some(sub_tracker) = self.sub_tracker_of(task) {
+                sub_tracker.accept_at(visitor, &task_path, depth + 1);
+            }
+        }
+        visitor.leave_tracker(path, depth, self);
+    }
+}
@@ -0,0 +1,151 @@
+//! Submodule defining the I/O performed by the process while a task runs, as
+//! the bytes read and written and the number of read and write syscalls.
+
+use std::ops::{Add, Sub};
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+/// The I/O counters of the process.
+///
+/// Read with [`IoCounters::now`], it holds the I/O performed since the
+/// process started; subtracting two readings gives the I/O performed in
+/// between.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeDelta;
+/// use time_requirements::io::IoCounters;
+///
+/// let io = IoCounters { read_bytes: 3 << 20, written_bytes: 1 << 20, ..IoCounters::default() };
+/// assert_eq!(io.total_bytes(), 4 << 20);
+/// assert_eq!(io.throughput(TimeDelta::seconds(2)), f64::from(2 << 20));
+/// ```
+pub struct IoCounters {
+    /// The bytes read, including from the page cache, pipes and sockets.
+    pub read_bytes: u64,
+    /// The bytes written, including to the page cache, pipes and sockets.
+    pub written_bytes: u64,
+    /// The number of read syscalls.
+    pub read_syscalls: u64,
+    /// The number of write syscalls.
+    pub write_syscalls: u64,
+    /// The bytes actually fetched from the storage layer.
+    pub storage_read_bytes: u64,
+    /// The bytes actually sent to the storage layer.
+    pub storage_written_bytes: u64,
+}
+
+impl IoCounters {
+    #[must_use]
+    /// Returns the I/O performed so far by the process, or `None` on
+    /// platforms where it cannot be measured.
+    ///
+    /// On Linux, the counters are read from `/proc/self/io`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use time_requirements::io::IoCounters;
+    ///
+    /// if let Some(before) = IoCounters::now() {
+    ///     let path = std::env::temp_dir().join("io_counters.txt");
+    ///     std::fs::File::create(&path).unwrap().write_all(&[0; 4096]).unwrap();
+    ///     let io = IoCounters::now().unwrap() - before;
+    ///     assert!(io.written_bytes >= 4096);
+    ///     assert!(io.write_syscalls >= 1);
+    ///     std::fs::remove_file(path).ok();
+    /// }
+    /// ```
+    pub fn now() -> Option<Self> {
+        #[cfg(target_os = "linux")]
+        {
+            let io = std::fs::read_to_string("/proc/self/io").ok()?;
+            let field = |name: &str| {
+                io.lines()
+                    .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+                    .and_then(|value| value.trim().parse().ok())
+            };
+            Some(Self {
+                read_bytes: field("rchar")?,
+                written_bytes: field("wchar")?,
+                read_syscalls: field("syscr")?,
+                write_syscalls: field("syscw")?,
+                storage_read_bytes: field("read_bytes")?,
+                storage_written_bytes: field("write_bytes")?,
+            })
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            None
+        }
+    }
+
+    #[must_use]
+    /// Returns the bytes read and written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::io::IoCounters;
+    ///
+    /// let io = IoCounters { read_bytes: 100, written_bytes: 50, ..IoCounters::default() };
+    /// assert_eq!(io.total_bytes(), 150);
+    /// ```
+    pub fn total_bytes(&self) -> u64 {
+        self.read_bytes + self.written_bytes
+    }
+
+    #[must_use]
+    /// Returns the bytes read and written per second over the provided
+    /// wall-clock time, or zero if the wall-clock time is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use time_requirements::io::IoCounters;
+    ///
+    /// let io = IoCounters { read_bytes: 1000, ..IoCounters::default() };
+    /// assert_eq!(io.throughput(TimeDelta::milliseconds(500)), 2000.0);
+    /// assert_eq!(io.throughput(TimeDelta::zero()), 0.0);
+    /// ```
+    pub fn throughput(&self, wall_time: chrono::TimeDelta) -> f64 {
+        crate::task::per_second(self.total_bytes(), wall_time)
+    }
+}
+
+impl Add for IoCounters {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            read_bytes: self.read_bytes + other.read_bytes,
+            written_bytes: self.written_bytes + other.written_bytes,
+            read_syscalls: self.read_syscalls + other.read_syscalls,
+            write_syscalls: self.write_syscalls + other.write_syscalls,
+            storage_read_bytes: self.storage_read_bytes + other.storage_read_bytes,
+            storage_written_bytes: self.storage_written_bytes + other.storage_written_bytes,
+        }
+    }
+}
+
+impl Sub for IoCounters {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            read_bytes: self.read_bytes.saturating_sub(other.read_bytes),
+            written_bytes: self.written_bytes.saturating_sub(other.written_bytes),
+            read_syscalls: self.read_syscalls.saturating_sub(other.read_syscalls),
+            write_syscalls: self.write_syscalls.saturating_sub(other.write_syscalls),
+            storage_read_bytes: self.storage_read_bytes.saturating_sub(other.storage_read_bytes),
+            storage_written_bytes: self
+                .storage_written_bytes
+                .saturating_sub(other.storage_written_bytes),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Submodule defining the iterator adapters recording the time spent
+//! iterating, either as a single task or as a task aggregated over the items.
+
+use crate::{guard::TaskGuard, outcome::Outcome, task::CompletedTask, time_tracker::TimeTracker};
+
+/// Extension trait adding timing adapters to all iterators, so that long
+/// iterator chains can be timed without breaking the chain style.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::prelude::*;
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// let total: u32 = ["1", "2", "3"]
+///     .into_iter()
+///     .map(|text| text.parse::<u32>().unwrap())
+///     .timed("parse", &mut tracker)
+///     .sum();
+///
+/// assert_eq!(total, 6);
+/// assert_eq!(tracker.get("parse").unwrap().items(), Some(3));
+/// ```
+pub trait TimedIterator: Iterator + Sized {
+    /// Records the whole iteration as a task with the provided name, counting
+    /// the items it yields.
+    ///
+    /// The task is started when the adapter is created and recorded once the
+    /// iterator is exhausted or the adapter is dropped, with the
+    /// [`Panicked`](crate::outcome::Outcome::Panicked) outcome if the thread
+    /// is panicking. As with
+    /// [`add_completed_task`](TimeTracker::add_completed_task), a
+    /// slash-separated name records the task under the named sub-trackers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let first_even = (1..100).timed("search/evens", &mut tracker).find(|n| n % 2 == 0);
+    ///
+    /// assert_eq!(first_even, Some(2));
+    /// assert_eq!(tracker.get("search/evens").unwrap().items(), Some(2));
+    /// ```
+    fn timed<'a, S: ToString + ?Sized>(
+        self,
+        name: &S,
+        tracker: &'a mut TimeTracker,
+    ) -> Timed<'a, Self> {
+        let mut guard = tracker.guard(name.to_string());
+        guard.add_items(0);
+        Timed { iter: self, guard: Some(guard) }
+    }
+
+    /// Records the production of each item as a repetition of the task with
+    /// the provided name, which aggregates the time and the number of items,
+    /// so that the report can show the time per item.
+    ///
+    /// Only the time spent producing the items is recorded, that is the time
+    /// spent in the adapters preceding this one, and not the time the
+    /// consumer spends on each item. The repetitions are aggregated by the
+    /// adapter and recorded at once when the iterator is exhausted or the
+    /// adapter is dropped, with the
+    /// [`Panicked`](crate::outcome::Outcome::Panicked) outcome if the thread
+    /// is panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use time_requirements::{clock::MockClock, prelude::*};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let parsed: Vec<u32> = ["1", "2", "3", "4"]
+    ///     .into_iter()
+    ///     .map(|text| {
+    ///         clock.advance(TimeDelta::milliseconds(5));
+    ///         text.parse::<u32>().unwrap()
+    ///     })
+    ///     .timed_each("parse", &mut tracker)
+    ///     .collect();
+    ///
+    /// assert_eq!(parsed, [1, 2, 3, 4]);
+    /// let task = tracker.get("parse").unwrap();
+    /// assert_eq!(tracker.tasks().count(), 1);
+    /// assert_eq!(task.items(), Some(4));
+    /// assert_eq!(task.time(), TimeDelta::milliseconds(20));
+    /// assert_eq!(task.time_per_item(), Some(TimeDelta::milliseconds(5)));
+    ///
+    /// // Stopping early records the items produced once the adapter is dropped.
+    /// let invalid = ["5", "x", "7"]
+    ///     .into_iter()
+    ///     .timed_each("parse", &mut tracker)
+    ///     .find(|text| text.parse::<u32>().is_err());
+    /// assert_eq!(invalid, Some("x"));
+    /// assert_eq!(tracker.get("parse").unwrap().items(), Some(6));
+    /// ```
+    fn timed_each<'a, S: ToString + ?Sized>(
+        self,
+        name: &S,
+        tracker: &'a mut TimeTracker,
+    ) -> TimedEach<'a, Self> {
+        TimedEach { iter: self, name: name.to_string(), tracker, completed: None }
+    }
+}
+
+impl<I: Iterator> TimedIterator for I {}
+
+/// Iterator adapter recording the whole iteration as a single task, created
+/// by [`TimedIterator::timed`].
+pub struct Timed<'a, I> {
+    /// The timed iterator.
+    iter: I,
+    /// The guard recording the task, dropped once the iterator is exhausted.
+    guard: Option<TaskGuard<'a>>,
+}
+
+impl<I: Iterator> Iterator for Timed<'_, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let guard = self.guard.as_mut()?;
+        if let Some(item) = self.iter.next() {
+            guard.add_items(1);
+            Some(item)
+        } else {
+            self.guard = None;
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.guard.is_some() { self.iter.size_hint() } else { (0, Some(0)) }
+    }
+}
+
+/// Iterator adapter recording the production of each item as a repetition of
+/// the same task, created by [`TimedIterator::timed_each`].
+pub struct TimedEach<'a, I> {
+    /// The timed iterator.
+    iter: I,
+    /// The name of the task aggregating the items.
+    name: String,
+    /// The tracker recording the task.
+    tracker: &'a mut TimeTracker,
+    /// The repetitions of the task aggregated so far, not yet recorded.
+    completed: Option<CompletedTask>,
+}
+
+impl<I> TimedEach<'_, I> {
+    /// Records the repetitions aggregated so far into the tracker, extending
+    /// the task with the same name if any.
+    fn flush(&mut self) {
+        if let Some(completed) = self.completed.take() {
+            self.tracker.add_or_extend_completed_task(completed);
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for TimedEach<'_, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut task = self.tracker.start_task(&self.name);
+        let Some(item) = self.iter.next() else {
+            self.flush();
+            return None;
+        };
+        task.add_items(1);
+        let repetition = task.complete();
+        match &mut self.completed {
+            Some(completed) => completed.extend(&repetition),
+            None => self.completed = Some(repetition),
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> Drop for TimedEach<'_, I> {
+    fn drop(&mut self) {
+        if std::thread::panicking()
+            && let Some(completed) = &mut self.completed
+        {
+            completed.outcome = Outcome::Panicked { message: None };
+        }
+        self.flush();
+    }
+}
@@ -1,10 +1,31 @@
 #![doc = include_str!("../README.md")]
 
+pub mod allocations;
+pub mod clock;
+pub mod command;
+pub mod cpu;
+pub mod guard;
+pub mod hierarchy;
+pub mod io;
+pub mod iterator;
+pub mod memory;
+pub mod metadata;
+mod nanoseconds;
+pub mod outcome;
 pub mod report;
+#[cfg(target_os = "linux")]
+mod rusage;
+pub mod scheduling;
 pub mod task;
 pub mod time_tracker;
+pub mod timed_io;
 
 /// Prelude module to re-export commonly used items.
 pub mod prelude {
-    pub use crate::{report::Report, task::Task, time_tracker::TimeTracker};
+    pub use crate::{
+        iterator::TimedIterator,
+        report::{Column, Report, ReportOptions, SortBy, TableStyle},
+        task::Task,
+        time_tracker::TimeTracker,
+    };
 }
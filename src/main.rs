@@ -0,0 +1,176 @@
+//! Command line interface timing shell commands into a tracker file, so that
+//! shell scripts and Makefiles can contribute to the same tracker as Rust
+//! code.
+//!
+//! ```text
+//! time-requirements run --tracker build.json --name "step" -- cmd args
+//! ```
+
+use std::{
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode},
+};
+
+use time_requirements::{prelude::*, task::CompletedTask};
+
+/// The usage message of the command line interface.
+const USAGE: &str = "\
+Usage: time-requirements run --tracker <FILE> [--name <NAME>] -- <COMMAND> [ARGS]...
+
+Runs the command and appends its timing to the JSON tracker file, creating the
+file if needed, then exits with the exit code of the command, even if the
+tracker file cannot be updated.
+
+Options:
+  --tracker <FILE>  The JSON file of the tracker to append the task to
+  --name <NAME>     The name of the task, slash-separated to nest it under
+                    sub-trackers, the file name of the command by default
+  -h, --help        Print this message";
+
+#[derive(Debug)]
+/// The arguments of the `run` subcommand.
+struct RunArguments {
+    /// The path of the JSON file of the tracker.
+    tracker: PathBuf,
+    /// The name of the task, if provided.
+    name: Option<String>,
+    /// The program to run.
+    program: String,
+    /// The arguments of the program.
+    arguments: Vec<String>,
+}
+
+impl RunArguments {
+    /// Parses the arguments following the `run` subcommand.
+    fn parse(mut arguments: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut tracker = None;
+        let mut name = None;
+        loop {
+            match arguments.next().as_deref() {
+                Some("--") => break,
+                Some("--tracker") => {
+                    tracker = Some(arguments.next().ok_or("--tracker requires a value")?);
+                }
+                Some("--name") => {
+                    name = Some(arguments.next().ok_or("--name requires a value")?);
+                }
+                Some(other) => return Err(format!("unexpected argument '{other}'")),
+                None => return Err("missing '--' before the command".to_owned()),
+            }
+        }
+        Ok(Self {
+            tracker: tracker.ok_or("--tracker is required")?.into(),
+            name,
+            program: arguments.next().ok_or("missing command to run")?,
+            arguments: arguments.collect(),
+        })
+    }
+
+    /// Returns the name of the task, the file name of the program by default.
+    fn task_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| {
+            Path::new(&self.program)
+                .file_name()
+                .map_or_else(|| self.program.clone(), |name| name.to_string_lossy().into_owned())
+        })
+    }
+
+    /// Returns the path of the file next to the tracker file with the
+    /// provided extension appended to its name.
+    fn sibling(&self, extension: &str) -> PathBuf {
+        let mut path = self.tracker.clone().into_os_string();
+        path.push(".");
+        path.push(extension);
+        path.into()
+    }
+
+    /// Loads the tracker file, or returns the provided new tracker if it does
+    /// not exist yet.
+    fn load_tracker(&self, new_tracker: TimeTracker) -> Result<TimeTracker, String> {
+        match fs::read_to_string(&self.tracker) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|error| format!("invalid tracker '{}': {error}", self.tracker.display())),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(new_tracker),
+            Err(error) => Err(format!("cannot read tracker '{}': {error}", self.tracker.display())),
+        }
+    }
+
+    /// Appends the provided task to the tracker file.
+    ///
+    /// The file is read and replaced while holding an advisory lock on the
+    /// sibling `.lock` file, so that concurrent runs appending to the same
+    /// tracker do not lose each other's tasks, and is replaced by renaming a
+    /// sibling `.tmp` file over it, so that it is never left half written.
+    fn record(&self, new_tracker: TimeTracker, task: CompletedTask) -> Result<(), String> {
+        let lock_path = self.sibling("lock");
+        let lock = File::create(&lock_path)
+            .and_then(|lock| lock.lock().map(|()| lock))
+            .map_err(|error| format!("cannot lock '{}': {error}", lock_path.display()))?;
+
+        let mut tracker = self.load_tracker(new_tracker)?;
+        tracker.add_completed_task(task);
+        let json = serde_json::to_string(&tracker).map_err(|error| error.to_string())?;
+        let temporary_path = self.sibling("tmp");
+        fs::write(&temporary_path, json)
+            .and_then(|()| fs::rename(&temporary_path, &self.tracker))
+            .map_err(|error| {
+                format!("cannot write tracker '{}': {error}", self.tracker.display())
+            })?;
+
+        drop(lock);
+        Ok(())
+    }
+
+    /// Runs the command, then appends it to the tracker file, returning the
+    /// exit code of the command.
+    ///
+    /// The tracker file is only read once the command completed, so that a
+    /// tracker which cannot be read or written is reported without
+    /// preventing the command from running or changing its exit code.
+    fn run(self) -> Result<ExitCode, String> {
+        // Created before running the command, so that a tracker file created
+        // by this run starts before its task.
+        let name = self.tracker.file_stem().unwrap_or(self.tracker.as_os_str());
+        let new_tracker = TimeTracker::new(&name.to_string_lossy());
+
+        let mut scratch = TimeTracker::new("scratch");
+        let result =
+            scratch.run_command("command", Command::new(&self.program).args(&self.arguments));
+        let task = scratch.tasks().next().expect("the command is recorded").clone();
+
+        if let Err(error) = self.record(new_tracker, task.with_name(&self.task_name())) {
+            eprintln!("error: {error}");
+        }
+
+        let status = result.map_err(|error| format!("cannot run '{}': {error}", self.program))?;
+        Ok(status
+            .code()
+            .and_then(|code| u8::try_from(code).ok())
+            .map_or(ExitCode::FAILURE, ExitCode::from))
+    }
+}
+
+fn main() -> ExitCode {
+    let mut arguments = std::env::args().skip(1);
+    let run = match arguments.next().as_deref() {
+        Some("run") => RunArguments::parse(arguments),
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Some(other) => Err(format!("unknown subcommand '{other}'")),
+        None => Err("missing subcommand".to_owned()),
+    };
+    match run {
+        Ok(run) => run.run().unwrap_or_else(|error| {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }),
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
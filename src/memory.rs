@@ -0,0 +1,227 @@
+//! Submodule defining the memory used by the process while a task runs, as
+//! its resident set size at the start and end of the task and its peak.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// A reading of the resident set size of the process, in bytes.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::memory::MemorySample;
+///
+/// if let Some(sample) = MemorySample::now() {
+///     assert!(sample.rss > 0);
+///     assert!(sample.peak_rss >= sample.rss);
+/// }
+/// ```
+pub struct MemorySample {
+    /// The current resident set size.
+    pub rss: u64,
+    /// The highest resident set size reached since the process started.
+    pub peak_rss: u64,
+}
+
+impl MemorySample {
+    #[must_use]
+    /// Returns the current and peak resident set size of the process, or
+    /// `None` on platforms where it cannot be measured.
+    ///
+    /// On Linux, the current size is read from `/proc/self/status` and the
+    /// peak from `getrusage`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::memory::MemorySample;
+    ///
+    /// assert_eq!(MemorySample::now().is_some(), cfg!(target_os = "linux"));
+    /// ```
+    pub fn now() -> Option<Self> {
+        #[cfg(target_os = "linux")]
+        {
+            let status = std::fs::read_to_string("/proc/self/status").ok()?;
+            let rss = status_kilobytes(&status, "VmRSS:")? * 1024;
+            let peak_rss = max_rss().or_else(|| status_kilobytes(&status, "VmHWM:"))? * 1024;
+            Some(Self { rss, peak_rss: peak_rss.max(rss) })
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            None
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+/// The resident set size of the process at the start and end of a task and
+/// its peak while the task ran, in bytes.
+///
+/// The sizes at the start and end are unknown for the tasks running a child
+/// process, of which only the peak is reported by the operating system.
+///
+/// The operating system only records the peak since the process started,
+/// so the peak of a task is exact when it raised that peak, and otherwise
+/// falls back to the largest of its start and end sizes, a lower bound of the
+/// actual peak flagged by [`peak_exact`](Self::peak_exact) being `false`.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::memory::{MemorySample, MemoryUsage};
+///
+/// let start = MemorySample { rss: 100, peak_rss: 150 };
+/// let end = MemorySample { rss: 120, peak_rss: 400 };
+/// let usage = MemoryUsage::between(start, end);
+/// assert_eq!(usage.start_rss, Some(100));
+/// assert_eq!(usage.end_rss, Some(120));
+/// assert_eq!(usage.peak_rss, 400);
+/// assert!(usage.peak_exact);
+/// assert_eq!(usage.delta(), Some(20));
+/// assert_eq!(usage.peak_increase(), Some(300));
+/// ```
+pub struct MemoryUsage {
+    /// The resident set size when the task started, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_rss: Option<u64>,
+    /// The resident set size when the task completed, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_rss: Option<u64>,
+    /// The peak resident set size while the task ran.
+    pub peak_rss: u64,
+    /// Whether the peak was measured, rather than estimated as the largest of
+    /// the start and end sizes because the task did not raise the peak of the
+    /// process.
+    #[serde(default)]
+    pub peak_exact: bool,
+}
+
+impl MemoryUsage {
+    #[must_use]
+    /// Returns the memory usage of a task from the samples read when it
+    /// started and when it completed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::memory::{MemorySample, MemoryUsage};
+    ///
+    /// // The peak of the process did not move: the task did not raise it.
+    /// let start = MemorySample { rss: 100, peak_rss: 500 };
+    /// let end = MemorySample { rss: 80, peak_rss: 500 };
+    /// let usage = MemoryUsage::between(start, end);
+    /// assert_eq!(usage.peak_rss, 100);
+    /// assert!(!usage.peak_exact);
+    /// ```
+    pub fn between(start: MemorySample, end: MemorySample) -> Self {
+        let peak_exact = end.peak_rss > start.peak_rss;
+        let peak_rss = if peak_exact { end.peak_rss } else { 0 };
+        Self {
+            start_rss: Some(start.rss),
+            end_rss: Some(end.rss),
+            peak_rss: peak_rss.max(start.rss).max(end.rss),
+            peak_exact,
+        }
+    }
+
+    #[must_use]
+    /// Returns the memory usage of which only the exact peak is known, as
+    /// for a child process.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::memory::MemoryUsage;
+    ///
+    /// let usage = MemoryUsage::from_peak(4096);
+    /// assert_eq!(usage.peak_rss, 4096);
+    /// assert!(usage.peak_exact);
+    /// assert_eq!(usage.start_rss, None);
+    /// assert_eq!(usage.delta(), None);
+    /// ```
+    pub fn from_peak(peak_rss: u64) -> Self {
+        Self { start_rss: None, end_rss: None, peak_rss, peak_exact: true }
+    }
+
+    #[must_use]
+    /// Returns the change of the resident set size between the start and the
+    /// end of the task, negative if memory was released, if both are known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::memory::MemoryUsage;
+    ///
+    /// let usage =
+    ///     MemoryUsage { start_rss: Some(300), end_rss: Some(100), peak_rss: 300, peak_exact: true };
+    /// assert_eq!(usage.delta(), Some(-200));
+    /// ```
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn delta(&self) -> Option<i64> {
+        Some(self.end_rss? as i64 - self.start_rss? as i64)
+    }
+
+    #[must_use]
+    /// Returns how far the peak resident set size rose above the size at the
+    /// start of the task, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::memory::MemoryUsage;
+    ///
+    /// let usage =
+    ///     MemoryUsage { start_rss: Some(100), end_rss: Some(100), peak_rss: 1000, peak_exact: true };
+    /// assert_eq!(usage.peak_increase(), Some(900));
+    /// ```
+    pub fn peak_increase(&self) -> Option<u64> {
+        Some(self.peak_rss.saturating_sub(self.start_rss?))
+    }
+
+    #[must_use]
+    /// Returns the memory usage spanning this task followed by the provided
+    /// one, starting with the size of the former, ending with the size of
+    /// the latter and peaking at the highest of their peaks, which is exact
+    /// when that highest peak is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::memory::MemoryUsage;
+    ///
+    /// let load =
+    ///     MemoryUsage { start_rss: Some(100), end_rss: Some(900), peak_rss: 1000, peak_exact: true };
+    /// let free =
+    ///     MemoryUsage { start_rss: Some(900), end_rss: Some(200), peak_rss: 900, peak_exact: false };
+    /// assert_eq!(
+    ///     load.then(free),
+    ///     MemoryUsage { start_rss: Some(100), end_rss: Some(200), peak_rss: 1000, peak_exact: true }
+    /// );
+    /// ```
+    pub fn then(self, next: Self) -> Self {
+        let peak = if next.peak_rss > self.peak_rss { next } else { self };
+        Self {
+            start_rss: self.start_rss,
+            end_rss: next.end_rss,
+            peak_rss: peak.peak_rss,
+            peak_exact: peak.peak_exact,
+        }
+    }
+}
+
+/// Returns the value in kilobytes of the provided field of the contents of
+/// `/proc/self/status`, if present.
+#[cfg(target_os = "linux")]
+fn status_kilobytes(status: &str, field: &str) -> Option<u64> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix(field))
+        .and_then(|value| value.trim().trim_end_matches("kB").trim().parse().ok())
+}
+
+/// Returns the peak resident set size of the process in kilobytes, as read
+/// with `getrusage`.
+#[cfg(target_os = "linux")]
+fn max_rss() -> Option<u64> {
+    u64::try_from(crate::rusage::rusage(libc::RUSAGE_SELF)?.ru_maxrss).ok()
+}
@@ -0,0 +1,221 @@
+//! Submodule defining the metadata attached to a task, such as tags,
+//! key-value attributes and free-form notes.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+/// The metadata attached to a task.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::metadata::Metadata;
+///
+/// let mut metadata = Metadata::default();
+/// assert!(metadata.is_empty());
+///
+/// metadata.add_tag("io");
+/// metadata.set_attribute("input", "spectra.mgf");
+/// metadata.add_note("Cold cache");
+/// assert!(metadata.has_tag("io"));
+/// assert_eq!(metadata.attribute("input"), Some("spectra.mgf"));
+/// assert_eq!(metadata.notes(), ["Cold cache"]);
+/// ```
+pub struct Metadata {
+    /// The tags of the task.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    tags: BTreeSet<String>,
+    /// The key-value attributes of the task.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    attributes: BTreeMap<String, String>,
+    /// The free-form notes of the task.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    notes: Vec<String>,
+}
+
+impl Metadata {
+    #[must_use]
+    /// Returns whether the metadata holds no tags, attributes nor notes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::metadata::Metadata;
+    ///
+    /// let mut metadata = Metadata::default();
+    /// assert!(metadata.is_empty());
+    /// metadata.add_note("Note");
+    /// assert!(!metadata.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.attributes.is_empty() && self.notes.is_empty()
+    }
+
+    /// Returns an iterator over the tags, in alphabetical order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::metadata::Metadata;
+    ///
+    /// let mut metadata = Metadata::default();
+    /// metadata.add_tag("io");
+    /// metadata.add_tag("cpu");
+    /// metadata.add_tag("io");
+    ///
+    /// let tags: Vec<&str> = metadata.tags().collect();
+    /// assert_eq!(tags, ["cpu", "io"]);
+    /// ```
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.tags.iter().map(String::as_str)
+    }
+
+    #[must_use]
+    /// Returns whether the provided tag is attached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::metadata::Metadata;
+    ///
+    /// let mut metadata = Metadata::default();
+    /// metadata.add_tag("io");
+    /// assert!(metadata.has_tag("io"));
+    /// assert!(!metadata.has_tag("cpu"));
+    /// ```
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(tag)
+    }
+
+    /// Attaches the provided tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::metadata::Metadata;
+    ///
+    /// let mut metadata = Metadata::default();
+    /// metadata.add_tag("io");
+    /// assert!(metadata.has_tag("io"));
+    /// ```
+    pub fn add_tag<S: ToString + ?Sized>(&mut self, tag: &S) {
+        self.tags.insert(tag.to_string());
+    }
+
+    #[must_use]
+    /// Returns the value of the attribute with the provided key, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::metadata::Metadata;
+    ///
+    /// let mut metadata = Metadata::default();
+    /// metadata.set_attribute("crate", "serde");
+    /// assert_eq!(metadata.attribute("crate"), Some("serde"));
+    /// assert_eq!(metadata.attribute("missing"), None);
+    /// ```
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(String::as_str)
+    }
+
+    /// Returns an iterator over the attributes, sorted by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::metadata::Metadata;
+    ///
+    /// let mut metadata = Metadata::default();
+    /// metadata.set_attribute("size", &42);
+    /// metadata.set_attribute("input", "spectra.mgf");
+    ///
+    /// let attributes: Vec<(&str, &str)> = metadata.attributes().collect();
+    /// assert_eq!(attributes, [("input", "spectra.mgf"), ("size", "42")]);
+    /// ```
+    pub fn attributes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.attributes.iter().map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Sets the attribute with the provided key, replacing its previous
+    /// value, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::metadata::Metadata;
+    ///
+    /// let mut metadata = Metadata::default();
+    /// metadata.set_attribute("size", &1);
+    /// metadata.set_attribute("size", &2);
+    /// assert_eq!(metadata.attribute("size"), Some("2"));
+    /// ```
+    pub fn set_attribute<K: ToString + ?Sized, V: ToString + ?Sized>(
+        &mut self,
+        key: &K,
+        value: &V,
+    ) {
+        self.attributes.insert(key.to_string(), value.to_string());
+    }
+
+    #[must_use]
+    /// Returns the notes, in the order in which they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::metadata::Metadata;
+    ///
+    /// let mut metadata = Metadata::default();
+    /// metadata.add_note("First");
+    /// metadata.add_note("Second");
+    /// assert_eq!(metadata.notes(), ["First", "Second"]);
+    /// ```
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    /// Adds the provided note.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::metadata::Metadata;
+    ///
+    /// let mut metadata = Metadata::default();
+    /// metadata.add_note("Cold cache");
+    /// assert_eq!(metadata.notes(), ["Cold cache"]);
+    /// ```
+    pub fn add_note<S: ToString + ?Sized>(&mut self, note: &S) {
+        self.notes.push(note.to_string());
+    }
+
+    /// Merges the provided metadata into this one, where the attributes of
+    /// the other metadata replace those with the same key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::metadata::Metadata;
+    ///
+    /// let mut metadata = Metadata::default();
+    /// metadata.add_tag("io");
+    /// metadata.set_attribute("size", &1);
+    ///
+    /// let mut other = Metadata::default();
+    /// other.add_tag("cpu");
+    /// other.set_attribute("size", &2);
+    /// other.add_note("Second run");
+    ///
+    /// metadata.merge(&other);
+    /// assert!(metadata.has_tag("io") && metadata.has_tag("cpu"));
+    /// assert_eq!(metadata.attribute("size"), Some("2"));
+    /// assert_eq!(metadata.notes(), ["Second run"]);
+    /// ```
+    pub fn merge(&mut self, other: &Metadata) {
+        self.tags.extend(other.tags.iter().cloned());
+        self.attributes
+            .extend(other.attributes.iter().map(|(key, value)| (key.clone(), value.clone())));
+        self.notes.extend(other.notes.iter().cloned());
+    }
+}
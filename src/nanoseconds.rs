@@ -0,0 +1,47 @@
+//! Submodule defining the serde helpers to (de)serialize a `TimeDelta` as a
+//! number of nanoseconds.
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serializes the provided `TimeDelta` as a number of nanoseconds.
+pub(crate) fn serialize<S: Serializer>(
+    time: &chrono::TimeDelta,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(time.num_nanoseconds().unwrap_or(i64::MAX))
+}
+
+/// Deserializes a `TimeDelta` from a number of nanoseconds.
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<chrono::TimeDelta, D::Error> {
+    i64::deserialize(deserializer).map(chrono::TimeDelta::nanoseconds)
+}
+
+/// Serde helpers to (de)serialize an optional `TimeDelta` as an optional
+/// number of nanoseconds.
+pub(crate) mod option {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes the provided optional `TimeDelta` as an optional number of
+    /// nanoseconds.
+    #[allow(clippy::ref_option)]
+    pub(crate) fn serialize<S: Serializer>(
+        time: &Option<chrono::TimeDelta>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match time {
+            Some(time) => super::serialize(time, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes an optional `TimeDelta` from an optional number of
+    /// nanoseconds.
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<chrono::TimeDelta>, D::Error> {
+        Option::<i64>::deserialize(deserializer)
+            .map(|time| time.map(chrono::TimeDelta::nanoseconds))
+    }
+}
@@ -0,0 +1,267 @@
+//! Submodule defining the outcome of a task, recording whether it
+//! succeeded, failed, panicked, was cancelled or was skipped.
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+/// The outcome of a task, with an optional message explaining it.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::outcome::Outcome;
+///
+/// let outcome = Outcome::failure("Disk full");
+/// assert!(outcome.is_failure());
+/// assert_eq!(outcome.status(), "failure");
+/// assert_eq!(outcome.message(), Some("Disk full"));
+///
+/// // Outcomes can be derived from results.
+/// let result: Result<(), String> = Err("Timeout".to_owned());
+/// assert_eq!(Outcome::from(&result), Outcome::failure("Timeout"));
+/// assert!(Outcome::from(&Ok::<_, String>(42)).is_success());
+/// ```
+pub enum Outcome {
+    #[default]
+    /// The task succeeded.
+    Success,
+    /// The task failed.
+    Failure {
+        /// The message explaining the failure, if any.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// The thread running the task panicked before the task completed.
+    Panicked {
+        /// The message explaining the panic, if any.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// The task was cancelled before completing.
+    Cancelled {
+        /// The message explaining the cancellation, if any.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// The task was skipped.
+    Skipped {
+        /// The message explaining why the task was skipped, if any.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+}
+
+impl Outcome {
+    #[must_use]
+    /// Returns a failure with the provided message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::outcome::Outcome;
+    ///
+    /// assert_eq!(Outcome::failure("Disk full").message(), Some("Disk full"));
+    /// ```
+    pub fn failure<S: ToString + ?Sized>(message: &S) -> Self {
+        Self::Failure { message: Some(message.to_string()) }
+    }
+
+    #[must_use]
+    /// Returns a cancellation with the provided message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::outcome::Outcome;
+    ///
+    /// assert_eq!(Outcome::cancelled("Interrupted").status(), "cancelled");
+    /// ```
+    pub fn cancelled<S: ToString + ?Sized>(message: &S) -> Self {
+        Self::Cancelled { message: Some(message.to_string()) }
+    }
+
+    #[must_use]
+    /// Returns a skip with the provided message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::outcome::Outcome;
+    ///
+    /// assert_eq!(Outcome::skipped("Up to date").status(), "skipped");
+    /// ```
+    pub fn skipped<S: ToString + ?Sized>(message: &S) -> Self {
+        Self::Skipped { message: Some(message.to_string()) }
+    }
+
+    #[must_use]
+    /// Returns whether the task succeeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::outcome::Outcome;
+    ///
+    /// assert!(Outcome::Success.is_success());
+    /// assert!(!Outcome::skipped("Up to date").is_success());
+    /// ```
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success)
+    }
+
+    #[must_use]
+    /// Returns whether the task failed, including by panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::outcome::Outcome;
+    ///
+    /// assert!(Outcome::Failure { message: None }.is_failure());
+    /// assert!(Outcome::Panicked { message: None }.is_failure());
+    /// assert!(!Outcome::cancelled("Interrupted").is_failure());
+    /// ```
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Failure { .. } | Self::Panicked { .. })
+    }
+
+    #[must_use]
+    /// Returns the name of the status of the outcome, as serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::outcome::Outcome;
+    ///
+    /// assert_eq!(Outcome::Success.status(), "success");
+    /// assert_eq!(Outcome::Failure { message: None }.status(), "failure");
+    /// ```
+    pub fn status(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure { .. } => "failure",
+            Self::Panicked { .. } => "panicked",
+            Self::Cancelled { .. } => "cancelled",
+            Self::Skipped { .. } => "skipped",
+        }
+    }
+
+    #[must_use]
+    /// Returns the message explaining the outcome, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::outcome::Outcome;
+    ///
+    /// assert_eq!(Outcome::Success.message(), None);
+    /// assert_eq!(Outcome::skipped("Up to date").message(), Some("Up to date"));
+    /// ```
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Self::Success => None,
+            Self::Failure { message }
+            | Self::Panicked { message }
+            | Self::Cancelled { message }
+            | Self::Skipped { message } => message.as_deref(),
+        }
+    }
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.message() {
+            Some(message) => write!(f, "{}: {message}", self.status()),
+            None => f.write_str(self.status()),
+        }
+    }
+}
+
+impl<T, E: std::fmt::Display> From<&Result<T, E>> for Outcome {
+    fn from(result: &Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Self::Success,
+            Err(error) => Self::failure(error),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+/// The number of tasks with each outcome.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::outcome::{Outcome, OutcomeCounts};
+///
+/// let counts: OutcomeCounts =
+///     [Outcome::Success, Outcome::Success, Outcome::failure("Disk full")].iter().collect();
+/// assert_eq!(counts.success, 2);
+/// assert_eq!(counts.failure, 1);
+/// assert_eq!(counts.total(), 3);
+/// assert!(!counts.all_succeeded());
+/// ```
+///
+/// Reports summarize the counts when some tasks did not succeed:
+///
+/// ```
+/// use time_requirements::{outcome::Outcome, prelude::*, report::MarkdownRenderer};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// tracker.add_completed_task("Fetch");
+/// tracker.add_completed_task_with("Compile", Outcome::failure("Syntax error"));
+/// let report = Report::from(tracker)
+///     .with_options(ReportOptions::default().with_columns([Column::Name, Column::Status]));
+///
+/// let markdown = report.render(&MarkdownRenderer);
+/// assert!(markdown.contains("Out of 2 tasks, 1 succeeded and 1 failed"));
+/// assert!(markdown.contains("failure: Syntax error"));
+/// ```
+pub struct OutcomeCounts {
+    /// The number of successful tasks.
+    pub success: usize,
+    /// The number of failed tasks, excluding those which panicked.
+    pub failure: usize,
+    /// The number of tasks which panicked.
+    pub panicked: usize,
+    /// The number of cancelled tasks.
+    pub cancelled: usize,
+    /// The number of skipped tasks.
+    pub skipped: usize,
+}
+
+impl OutcomeCounts {
+    /// Counts the provided outcome.
+    pub fn add(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Success => self.success += 1,
+            Outcome::Failure { .. } => self.failure += 1,
+            Outcome::Panicked { .. } => self.panicked += 1,
+            Outcome::Cancelled { .. } => self.cancelled += 1,
+            Outcome::Skipped { .. } => self.skipped += 1,
+        }
+    }
+
+    #[must_use]
+    /// Returns the total number of tasks counted.
+    pub fn total(&self) -> usize {
+        self.success + self.failure + self.panicked + self.cancelled + self.skipped
+    }
+
+    #[must_use]
+    /// Returns whether all the tasks counted succeeded.
+    pub fn all_succeeded(&self) -> bool {
+        self.success == self.total()
+    }
+}
+
+impl<'a> FromIterator<&'a Outcome> for OutcomeCounts {
+    fn from_iter<I: IntoIterator<Item = &'a Outcome>>(iter: I) -> Self {
+        let mut counts = Self::default();
+        for outcome in iter {
+            counts.add(outcome);
+        }
+        counts
+    }
+}
@@ -1,9 +1,22 @@
 //! Submodule defining the structs and methods for generating a report.
 
-use std::{io::Write, path::Path};
+use std::{
+    fmt::Display,
+    io::{IsTerminal, Write},
+    path::Path,
+};
 
-use chrono_humanize::{Accuracy, HumanTime, Tense};
-use tabled::{Table, Tabled, settings::Style};
+mod model;
+mod options;
+mod renderer;
+mod template;
+
+pub use model::{ReportLeaf, ReportModel, ReportRow, UNTRACKED_ROW_NAME};
+pub use options::{Column, ReportOptions, SortBy, TableStyle};
+pub use renderer::{
+    HtmlRenderer, JsonRenderer, MarkdownRenderer, PlainTextRenderer, Renderer, TreeRenderer,
+};
+pub use template::{TemplateContext, TemplateDuration, TemplateEngine, TemplateLeaf, TemplateTask};
 
 use crate::{prelude::TimeTracker, task::CompletedTask};
 
@@ -11,40 +24,39 @@ use crate::{prelude::TimeTracker, task::CompletedTask};
 pub struct Report {
     /// The time tracker to generate a report for.
     time_tracker: TimeTracker,
-}
-
-#[derive(Tabled)]
-struct TableRow<'a> {
-    name: &'a str,
-    time: String,
-    percentage: String,
+    /// The options customizing the report.
+    options: ReportOptions,
 }
 
 impl Report {
-    fn title(&self, depth: usize) -> String {
-        format!("{} Time Report for {}\n\n", "#".repeat(depth + 1), self.time_tracker.name())
-    }
-
-    fn description(&self) -> String {
-        let total_time = self.time_tracker.total_time();
-
-        format!(
-            "The total time spent on all tasks was {}.\n",
-            HumanTime::from(total_time).to_text_en(Accuracy::Rough, Tense::Present),
-        )
+    #[must_use]
+    /// Sets the options customizing the report.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("B"));
+    /// tracker.add_completed_task(Task::new("A"));
+    ///
+    /// let report: Report = tracker.into();
+    /// let report = report.with_options(ReportOptions::default().with_sort_by(SortBy::Name));
+    /// let tree = report.tree(false);
+    /// let lines: Vec<&str> = tree.lines().collect();
+    /// assert!(lines[1].starts_with("├── A"));
+    /// assert!(lines[2].starts_with("└── B"));
+    /// ```
+    pub fn with_options(mut self, options: ReportOptions) -> Self {
+        self.options = options;
+        self
     }
 
-    #[allow(clippy::cast_precision_loss)]
-    fn slowest_task_description(&self) -> Option<String> {
-        self.time_tracker.slowest_task().map(|task| {
-            let total_time = self.time_tracker.total_time();
-            format!(
-                "The slowest task was `{}` which took {} ({:.2}% of all time).",
-                task.name(),
-                HumanTime::from(task.time()).to_text_en(Accuracy::Precise, Tense::Present),
-                task.precise_percentage_over(total_time),
-            )
-        })
+    #[must_use]
+    /// Returns the options customizing the report.
+    pub fn options(&self) -> &ReportOptions {
+        &self.options
     }
 
     #[must_use]
@@ -53,31 +65,32 @@ impl Report {
     /// # Examples
     ///
     /// ```
-    /// use std::{thread, time::Duration};
+    /// use chrono::TimeDelta;
+    /// use time_requirements::{clock::MockClock, prelude::*};
     ///
-    /// use time_requirements::prelude::*;
+    /// let clock = MockClock::default();
     ///
     /// // No tasks
-    /// let tracker = TimeTracker::new("Project");
+    /// let tracker = TimeTracker::with_clock("Project", clock.clone());
     /// let report: Report = tracker.into();
     /// assert!(report.slowest_task().is_none());
     ///
     /// // One task
-    /// let mut tracker = TimeTracker::new("Project");
-    /// let task = Task::new("Only task");
-    /// thread::sleep(Duration::from_millis(10));
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let task = tracker.start_task("Only task");
+    /// clock.advance(TimeDelta::milliseconds(10));
     /// tracker.add_completed_task(task);
     /// let report: Report = tracker.into();
     /// assert_eq!(report.slowest_task().unwrap().name(), "Only task");
     ///
     /// // Multiple tasks
-    /// let mut tracker = TimeTracker::new("Project");
-    /// let task1 = Task::new("Short task");
-    /// thread::sleep(Duration::from_millis(10));
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let task1 = tracker.start_task("Short task");
+    /// clock.advance(TimeDelta::milliseconds(10));
     /// tracker.add_completed_task(task1);
     ///
-    /// let task2 = Task::new("Long task");
-    /// thread::sleep(Duration::from_millis(100));
+    /// let task2 = tracker.start_task("Long task");
+    /// clock.advance(TimeDelta::milliseconds(100));
     /// tracker.add_completed_task(task2);
     ///
     /// let report: Report = tracker.into();
@@ -87,43 +100,134 @@ impl Report {
         self.time_tracker.slowest_task()
     }
 
-    /// Returns an iterator over the sub-reports.
-    fn sub_reports(&self) -> impl Iterator<Item = Report> + '_ {
-        self.time_tracker.sub_trackers().iter().cloned().map(|time_tracker| Self { time_tracker })
+    #[must_use]
+    /// Returns the data model of the report, computed according to the
+    /// report options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// let report: Report = tracker.into();
+    ///
+    /// let model = report.model();
+    /// assert_eq!(model.name, "Project");
+    /// assert_eq!(model.rows[0].name, "Task");
+    /// ```
+    pub fn model(&self) -> ReportModel {
+        ReportModel::new(&self.time_tracker, &self.options)
     }
 
-    #[allow(clippy::cast_precision_loss)]
-    /// Returns the text of the report.
-    fn text(&self, depth: usize) -> String {
-        let total_time = self.time_tracker.total_time();
-        let rows = self.time_tracker.tasks().map(|task| {
-            TableRow {
-                name: task.name(),
-                time: HumanTime::from(task.time()).to_text_en(Accuracy::Precise, Tense::Present),
-                percentage: format!("{:.2}%", task.precise_percentage_over(total_time)),
-            }
-        });
-        let mut table = Table::new(rows);
-        table.with(Style::markdown());
-
-        let mut report = String::new();
-
-        report.push_str(&self.title(depth));
-        report.push_str(&self.description());
-
-        if let Some(description) = self.slowest_task_description() {
-            report.push_str(&description);
-        }
-
-        report.push_str("\n\n");
-        report.push_str(&table.to_string());
-
-        for sub_report in self.sub_reports() {
-            report.push_str("\n\n");
-            report.push_str(&sub_report.text((depth + 1).min(6)));
-        }
-
-        report
+    #[must_use]
+    /// Renders the report with the provided renderer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{prelude::*, report::HtmlRenderer};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// let report: Report = tracker.into();
+    ///
+    /// assert!(report.render(&HtmlRenderer).contains("<td>Task</td>"));
+    /// ```
+    pub fn render<R: Renderer + ?Sized>(&self, renderer: &R) -> String {
+        renderer.render(&self.model(), &self.options)
+    }
+
+    /// Renders the report through the provided user-supplied template engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - The template engine, which receives the
+    ///   [`TemplateContext`] of the report.
+    ///
+    /// # Errors
+    ///
+    /// If the template engine fails, its error will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{prelude::*, report::TemplateContext};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// let report: Report = tracker.into();
+    ///
+    /// let engine = |context: &TemplateContext| -> Result<String, String> {
+    ///     let slowest = context.slowest_task.as_ref().ok_or("no tasks")?;
+    ///     Ok(format!("Slowest step of {}: {}", context.name, slowest.name))
+    /// };
+    /// assert_eq!(report.render_template(&engine).unwrap(), "Slowest step of Project: Task");
+    /// ```
+    pub fn render_template<T: TemplateEngine + ?Sized>(
+        &self,
+        engine: &T,
+    ) -> Result<String, T::Error> {
+        engine.render(&TemplateContext::from(&self.model()))
+    }
+
+    /// Returns the hierarchy of the report as an indented tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `colored` - Whether to color the slowest entries with ANSI escape
+    ///   sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// let mut sub_tracker = TimeTracker::new("Sub Project");
+    /// sub_tracker.add_completed_task(Task::new("Sub Task"));
+    /// tracker.extend(sub_tracker);
+    ///
+    /// let report: Report = tracker.into();
+    /// let tree = report.tree(false);
+    /// let lines: Vec<&str> = tree.lines().collect();
+    /// assert_eq!(lines.len(), 4);
+    /// assert!(lines[0].starts_with("Project"));
+    /// assert!(lines[1].starts_with("├── Task"));
+    /// assert!(lines[2].starts_with("└── Sub Project"));
+    /// assert!(lines[3].starts_with("    └── Sub Task"));
+    /// assert!(!tree.contains('\x1b'));
+    ///
+    /// assert!(report.tree(true).contains('\x1b'));
+    /// ```
+    #[must_use]
+    pub fn tree(&self, colored: bool) -> String {
+        self.render(&TreeRenderer { colored })
+    }
+
+    /// Prints the hierarchy of the report as an indented tree to the
+    /// standard output.
+    ///
+    /// # Implementation Note
+    ///
+    /// The slowest entries are colored only when the standard output is a
+    /// terminal and the `NO_COLOR` environment variable is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// let report: Report = tracker.into();
+    /// report.print_tree();
+    /// ```
+    pub fn print_tree(&self) {
+        let colored = std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+        print!("{}", self.tree(colored));
     }
 
     /// Writes out the markdown report to a given file.
@@ -139,26 +243,19 @@ impl Report {
     /// # Examples
     ///
     /// ```
-    /// use std::{path::Path, thread, time::Duration};
-    ///
-    /// use time_requirements::prelude::*;
+    /// use time_requirements::{clock::MockClock, prelude::*};
     ///
     /// // Create current tracker with tasks and sub-tracker
-    /// let mut current_tracker = TimeTracker::new("Current Project");
-    /// let task = Task::new("Main Task");
-    /// thread::sleep(Duration::from_millis(100));
+    /// let clock = MockClock::default();
+    /// let mut current_tracker = TimeTracker::with_clock("Current Project", clock.clone());
+    /// let task = current_tracker.start_task("Main Task");
+    /// clock.advance(chrono::TimeDelta::milliseconds(100));
     /// current_tracker.add_completed_task(task);
     ///
     /// // Add a sub-tracker
-    /// let sub_tracker = TimeTracker::new("Sub Project");
+    /// let sub_tracker = TimeTracker::with_clock("Sub Project", clock.clone());
     /// current_tracker.extend(sub_tracker);
     ///
-    /// // Create previous tracker with same task time
-    /// let mut previous_tracker = TimeTracker::new("Previous Project");
-    /// let prev_task = Task::new("Main Task");
-    /// thread::sleep(Duration::from_millis(100)); // Same time
-    /// previous_tracker.add_completed_task(prev_task);
-    ///
     /// let mut report: Report = current_tracker.into();
     ///
     /// let temp_path = std::env::temp_dir().join("test_report.md");
@@ -167,9 +264,43 @@ impl Report {
     /// std::fs::remove_file(temp_path).ok(); // Clean up
     /// ```
     pub fn write<S: AsRef<Path> + ?Sized>(&self, report_path: &S) -> std::io::Result<()> {
+        self.write_with(report_path, &MarkdownRenderer)
+    }
+
+    /// Writes out the report rendered with the provided renderer to a given
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// * `report_path` - The path to the file to write the report to.
+    /// * `renderer` - The renderer to use.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be created or written to, an error will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{prelude::*, report::HtmlRenderer};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// let report: Report = tracker.into();
+    ///
+    /// let temp_path = std::env::temp_dir().join("test_report.html");
+    /// report.write_with(&temp_path, &HtmlRenderer).expect("Failed to write report");
+    /// assert!(std::fs::read_to_string(&temp_path).unwrap().starts_with("<section>"));
+    /// std::fs::remove_file(temp_path).ok(); // Clean up
+    /// ```
+    pub fn write_with<S: AsRef<Path> + ?Sized, R: Renderer + ?Sized>(
+        &self,
+        report_path: &S,
+        renderer: &R,
+    ) -> std::io::Result<()> {
         let mut file = std::fs::File::create(report_path)?;
 
-        writeln!(file, "{}", self.text(0))?;
+        writeln!(file, "{}", self.render(renderer))?;
 
         Ok(())
     }
@@ -178,6 +309,12 @@ impl Report {
 impl From<TimeTracker> for Report {
     /// Creates a new report from a time tracker.
     fn from(time_tracker: TimeTracker) -> Self {
-        Self { time_tracker }
+        Self { time_tracker, options: ReportOptions::default() }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.tree(false))
     }
 }
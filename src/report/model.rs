@@ -0,0 +1,379 @@
+//! Submodule defining the data model of a report, computed once from a
+//! tracker and shared by all the renderers.
+
+use super::ReportOptions;
+use crate::{
+    allocations::AllocationCounts,
+    cpu::CpuTime,
+    io::IoCounters,
+    memory::MemoryUsage,
+    metadata::Metadata,
+    outcome::{Outcome, OutcomeCounts},
+    scheduling::SchedulingEvents,
+    task::CompletedTask,
+    time_tracker::TimeTracker,
+};
+
+/// Returns the percentage of the provided total represented by the provided
+/// time, or zero if the total is zero.
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn percentage_of(time: chrono::TimeDelta, total: chrono::TimeDelta) -> f64 {
+    match (time.num_nanoseconds(), total.num_nanoseconds()) {
+        (Some(time), Some(total)) if total != 0 => time as f64 / total as f64 * 100.0,
+        _ => 0.0,
+    }
+}
+
+/// Returns the time the percentages of the rows of the provided tracker are
+/// relative to: its elapsed time once finished, so that they add up with the
+/// untracked time, and the wall-clock time covered by its tasks otherwise.
+pub(super) fn reference_time(tracker: &TimeTracker) -> chrono::TimeDelta {
+    if tracker.is_finished() { tracker.elapsed() } else { tracker.tracked_time() }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// An entry of the flattened list of the slowest tasks across the whole
+/// hierarchy, excluding the tasks summarizing sub-trackers.
+pub struct ReportLeaf {
+    /// The slash-separated path of the task, relative to the root tracker.
+    pub path: String,
+    /// The depth of the tracker containing the task, zero for the root.
+    pub depth: usize,
+    /// The time required by the task, serialized in nanoseconds.
+    #[serde(with = "crate::nanoseconds")]
+    pub time: chrono::TimeDelta,
+    /// The percentage of the time of the root tracker during which the task
+    /// ran, relative to its elapsed time once finished and to the wall-clock
+    /// time covered by its tasks otherwise.
+    pub root_percentage: f64,
+}
+
+impl ReportLeaf {
+    /// Appends to the provided leaves those of the provided tracker, recursing
+    /// into its sub-trackers.
+    fn collect(
+        tracker: &TimeTracker,
+        prefix: &str,
+        depth: usize,
+        root_time: chrono::TimeDelta,
+        leaves: &mut Vec<ReportLeaf>,
+    ) {
+        for task in tracker.tasks() {
+            let path = format!("{prefix}{}", task.name());
+            if let Some(sub_tracker) = tracker.sub_tracker_of(task) {
+                Self::collect(sub_tracker, &format!("{path}/"), depth + 1, root_time, leaves);
+            } else {
+                leaves.push(Self {
+                    path,
+                    depth,
+                    time: task.time(),
+                    root_percentage: percentage_of(task.time(), root_time),
+                });
+            }
+        }
+    }
+}
+
+/// The name of the row describing the time not covered by any task.
+pub const UNTRACKED_ROW_NAME: &str = "(untracked)";
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// A row of the report, describing a single task.
+pub struct ReportRow {
+    /// The name of the task.
+    pub name: String,
+    /// The start time of the task.
+    pub start: chrono::NaiveDateTime,
+    /// The end time of the task.
+    pub end: chrono::NaiveDateTime,
+    /// The time required by the task, including the time spent in the
+    /// sub-tracker it summarizes, serialized in nanoseconds.
+    #[serde(with = "crate::nanoseconds")]
+    pub time: chrono::TimeDelta,
+    /// The time required by the task, excluding the time spent in the
+    /// sub-trackers of the sub-tracker it summarizes, serialized in
+    /// nanoseconds.
+    #[serde(with = "crate::nanoseconds")]
+    pub self_time: chrono::TimeDelta,
+    /// The percentage of the time of the tracker during which the task ran,
+    /// relative to its elapsed time once finished, so that the rows add up
+    /// with the untracked time, and to the wall-clock time covered by its
+    /// tasks otherwise.
+    pub percentage: f64,
+    /// The percentage of the time of the root tracker of the report during
+    /// which the task ran, relative to the same time as the percentages of
+    /// its rows.
+    pub root_percentage: f64,
+    /// The peak number of tasks running concurrently with the task,
+    /// including itself.
+    pub concurrency: usize,
+    /// Whether the task summarizes a sub-tracker.
+    pub is_sub_tracker: bool,
+    /// The metadata attached to the task.
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub metadata: Metadata,
+    /// The outcome of the task.
+    pub outcome: Outcome,
+    /// The number of items processed by the task, if counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<u64>,
+    /// The number of bytes processed by the task, if counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processed_bytes: Option<u64>,
+    /// The time spent inside the reads and writes of the task, in
+    /// nanoseconds, if it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "crate::nanoseconds::option")]
+    pub io_time: Option<chrono::TimeDelta>,
+    /// The CPU time consumed while the task ran, if it was measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<CpuTime>,
+    /// The memory used by the process while the task ran, if it was
+    /// measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryUsage>,
+    /// The allocations performed on the thread of the task while it ran, if
+    /// they were counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<AllocationCounts>,
+    /// The I/O performed by the process while the task ran, if it was
+    /// measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io: Option<IoCounters>,
+    /// The context switches and page faults of the process while the task
+    /// ran, if they were counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheduling: Option<SchedulingEvents>,
+}
+
+impl ReportRow {
+    /// Creates a new row describing the provided task.
+    ///
+    /// # Arguments
+    ///
+    /// * `tracker` - The tracker the task belongs to.
+    /// * `task` - The task to describe.
+    /// * `root_time` - The time the root percentages are relative to.
+    fn new(tracker: &TimeTracker, task: &CompletedTask, root_time: chrono::TimeDelta) -> Self {
+        Self {
+            name: task.name().to_owned(),
+            start: task.start,
+            end: task.end,
+            time: task.time(),
+            self_time: tracker.task_self_time(task),
+            percentage: percentage_of(task.time(), reference_time(tracker)),
+            root_percentage: percentage_of(task.time(), root_time),
+            concurrency: tracker.concurrency_of(task),
+            is_sub_tracker: tracker.sub_tracker_of(task).is_some(),
+            metadata: task.metadata().clone(),
+            outcome: task.outcome().clone(),
+            items: task.items(),
+            processed_bytes: task.processed_bytes(),
+            io_time: task.io_time(),
+            cpu: task.cpu_time(),
+            memory: task.memory_usage(),
+            allocations: task.allocations(),
+            io: task.io_counters(),
+            scheduling: task.scheduling_events(),
+        }
+    }
+
+    /// Creates a new row describing the time of the provided tracker not
+    /// covered by any task, if the tracker was finished and there is any.
+    fn untracked(tracker: &TimeTracker, root_time: chrono::TimeDelta) -> Option<Self> {
+        let untracked_time = tracker.untracked_time();
+        if !tracker.is_finished() || untracked_time <= chrono::TimeDelta::zero() {
+            return None;
+        }
+        Some(Self {
+            name: UNTRACKED_ROW_NAME.to_owned(),
+            start: tracker.start(),
+            end: tracker.end(),
+            time: untracked_time,
+            self_time: untracked_time,
+            percentage: percentage_of(untracked_time, reference_time(tracker)),
+            root_percentage: percentage_of(untracked_time, root_time),
+            concurrency: 0,
+            is_sub_tracker: false,
+            metadata: Metadata::default(),
+            outcome: Outcome::Success,
+            items: None,
+            processed_bytes: None,
+            io_time: None,
+            cpu: None,
+            memory: None,
+            allocations: None,
+            io: None,
+            scheduling: None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// The data model of a report, holding the totals, the slowest task, the
+/// rows and the children of a tracker.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{prelude::*, report::ReportModel};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// tracker.add_completed_task(Task::new("Task"));
+/// let mut sub_tracker = TimeTracker::new("Sub Project");
+/// sub_tracker.add_completed_task(Task::new("Sub Task"));
+/// tracker.extend(sub_tracker);
+///
+/// let model = ReportModel::new(&tracker, &ReportOptions::default());
+/// assert_eq!(model.name, "Project");
+/// assert_eq!(model.rows.len(), 2);
+/// assert!(!model.rows[0].is_sub_tracker);
+/// assert!(model.rows[1].is_sub_tracker);
+/// assert_eq!(model.children.len(), 1);
+/// assert_eq!(model.children[0].name, "Sub Project");
+/// assert_eq!(model.total_time, tracker.total_time());
+/// assert_eq!(model.self_time, model.rows[0].self_time);
+/// assert_eq!(model.rows[1].self_time, model.children[0].self_time);
+/// assert!(model.untracked.is_none());
+///
+/// // Once finished, the time not covered by any task is reported.
+/// tracker.finish();
+/// let model = ReportModel::new(&tracker, &ReportOptions::default());
+/// let untracked = model.untracked.unwrap();
+/// assert_eq!(untracked.name, "(untracked)");
+/// assert_eq!(untracked.time, tracker.untracked_time());
+/// ```
+pub struct ReportModel {
+    /// The name of the tracker.
+    pub name: String,
+    /// The start time of the tracker.
+    pub start: chrono::NaiveDateTime,
+    /// The total time spent on all tasks, serialized in nanoseconds.
+    #[serde(with = "crate::nanoseconds")]
+    pub total_time: chrono::TimeDelta,
+    /// The time spent on the tasks of the tracker itself, excluding its
+    /// sub-trackers, serialized in nanoseconds.
+    #[serde(with = "crate::nanoseconds")]
+    pub self_time: chrono::TimeDelta,
+    /// The wall-clock time covered by at least one task, serialized in
+    /// nanoseconds.
+    #[serde(with = "crate::nanoseconds")]
+    pub tracked_time: chrono::TimeDelta,
+    /// The total time divided by the tracked time, which is one when no
+    /// tasks ran concurrently.
+    pub parallelism: f64,
+    /// The wall-clock time elapsed between the start and the end of the
+    /// tracker, serialized in nanoseconds.
+    #[serde(with = "crate::nanoseconds")]
+    pub elapsed: chrono::TimeDelta,
+    /// The number of tasks with each outcome across the hierarchy.
+    pub outcome_counts: OutcomeCounts,
+    /// The time spent on the successful tasks across the hierarchy,
+    /// serialized in nanoseconds.
+    #[serde(with = "crate::nanoseconds")]
+    pub successful_time: chrono::TimeDelta,
+    /// The time spent on the failed tasks across the hierarchy, serialized in
+    /// nanoseconds.
+    #[serde(with = "crate::nanoseconds")]
+    pub failed_time: chrono::TimeDelta,
+    /// The row describing the wall-clock time not covered by any task, if the
+    /// tracker was finished.
+    pub untracked: Option<ReportRow>,
+    /// The slowest task of the tracker, if any.
+    pub slowest_task: Option<ReportRow>,
+    /// The rows shown in the report, according to the report options.
+    pub rows: Vec<ReportRow>,
+    /// The models of the sub-trackers shown in the report, in the same order
+    /// as the rows summarizing them.
+    pub children: Vec<ReportModel>,
+    /// The slowest tasks across the whole hierarchy, if requested by the
+    /// report options, only populated for the root tracker.
+    pub slowest_leaves: Vec<ReportLeaf>,
+}
+
+impl ReportModel {
+    #[must_use]
+    /// Computes the model of the report of the provided tracker.
+    ///
+    /// # Arguments
+    ///
+    /// * `tracker` - The tracker to describe.
+    /// * `options` - The options selecting the rows and children to include.
+    pub fn new(tracker: &TimeTracker, options: &ReportOptions) -> Self {
+        let root_time = reference_time(tracker);
+        let mut model = Self::at_depth(tracker, options, 0, root_time);
+
+        if let Some(top) = options.top_leaves() {
+            let mut leaves = Vec::new();
+            ReportLeaf::collect(tracker, "", 0, root_time, &mut leaves);
+            leaves.sort_by_key(|leaf| std::cmp::Reverse(leaf.time));
+            leaves.truncate(top);
+            model.slowest_leaves = leaves;
+        }
+
+        model
+    }
+
+    /// Computes the model of the report of the provided tracker, located at
+    /// the provided depth of the hierarchy.
+    fn at_depth(
+        tracker: &TimeTracker,
+        options: &ReportOptions,
+        depth: usize,
+        root_time: chrono::TimeDelta,
+    ) -> Self {
+        let tasks = options.visible_tasks(tracker);
+        let children = if options.includes_depth(depth + 1) {
+            tasks
+                .iter()
+                .filter_map(|task| tracker.sub_tracker_of(task))
+                .map(|sub_tracker| Self::at_depth(sub_tracker, options, depth + 1, root_time))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            name: tracker.name().to_owned(),
+            start: tracker.start(),
+            total_time: tracker.total_time(),
+            self_time: tracker.self_time(),
+            tracked_time: tracker.tracked_time(),
+            parallelism: tracker.parallelism(),
+            elapsed: tracker.elapsed(),
+            outcome_counts: tracker.outcome_counts(),
+            successful_time: tracker.successful_time(),
+            failed_time: tracker.failed_time(),
+            untracked: ReportRow::untracked(tracker, root_time),
+            slowest_task: tracker
+                .slowest_task()
+                .map(|task| ReportRow::new(tracker, task, root_time)),
+            rows: tasks.into_iter().map(|task| ReportRow::new(tracker, task, root_time)).collect(),
+            children,
+            slowest_leaves: Vec::new(),
+        }
+    }
+
+    /// Returns an iterator over the rows, each paired with the model of the
+    /// sub-tracker it summarizes, if that is included in the report.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{prelude::*, report::ReportModel};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// tracker.extend(TimeTracker::new("Sub Project"));
+    ///
+    /// let model = ReportModel::new(&tracker, &ReportOptions::default());
+    /// let pairs: Vec<_> = model.rows_with_children().collect();
+    /// assert!(pairs[0].1.is_none());
+    /// assert_eq!(pairs[1].1.unwrap().name, "Sub Project");
+    /// ```
+    pub fn rows_with_children(&self) -> impl Iterator<Item = (&ReportRow, Option<&ReportModel>)> {
+        let mut children = self.children.iter();
+        self.rows
+            .iter()
+            .map(move |row| (row, if row.is_sub_tracker { children.next() } else { None }))
+    }
+}
@@ -0,0 +1,612 @@
+//! Submodule defining the options to customize a report.
+
+use tabled::{Table, settings::Style};
+
+use super::model::{percentage_of, reference_time};
+use crate::{task::CompletedTask, time_tracker::TimeTracker};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// The order in which the tasks are listed in a report.
+pub enum SortBy {
+    #[default]
+    /// The order in which the tasks were added to the tracker.
+    Insertion,
+    /// From the slowest to the fastest task.
+    Duration,
+    /// Alphabetically by the name of the task.
+    Name,
+    /// From the earliest to the latest started task.
+    Start,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A column which may be shown in the table of a report.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{prelude::*, report::MarkdownRenderer};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// tracker.add_completed_task(Task::new("Parse").with_attribute("input", "spectra.mgf"));
+/// let report = Report::from(tracker).with_options(ReportOptions::default().with_columns([
+///     Column::Name,
+///     Column::Time,
+///     Column::Attribute("input".to_owned()),
+/// ]));
+///
+/// let markdown = report.render(&MarkdownRenderer);
+/// assert!(markdown.contains("| input"));
+/// assert!(markdown.contains("spectra.mgf"));
+/// ```
+///
+/// The time of the tasks counting the work they did can be normalized by it:
+///
+/// ```
+/// use time_requirements::{clock::MockClock, prelude::*, report::MarkdownRenderer};
+///
+/// let clock = MockClock::default();
+/// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+/// let mut task = tracker.start_task("Annotate");
+/// task.add_items(10_000);
+/// clock.advance(chrono::TimeDelta::seconds(2));
+/// tracker.add_completed_task(task);
+/// let report = Report::from(tracker).with_options(ReportOptions::default().with_columns([
+///     Column::Name,
+///     Column::Items,
+///     Column::ItemsPerSecond,
+///     Column::TimePerItem,
+/// ]));
+///
+/// let markdown = report.render(&MarkdownRenderer);
+/// assert!(markdown.contains("| Annotate | 10000 | 5000.00 | 200.00 µs     |"));
+/// ```
+///
+/// The CPU time of the tasks measuring it is shown next to their wall time:
+///
+/// ```
+/// use time_requirements::{prelude::*, report::MarkdownRenderer};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// let task = tracker.start_task("Sum").with_cpu_time();
+/// let _sum: u64 = (0..1_000_000).sum();
+/// tracker.add_completed_task(task);
+/// let report = Report::from(tracker).with_options(ReportOptions::default().with_columns([
+///     Column::Name,
+///     Column::Time,
+///     Column::CpuTime,
+///     Column::CpuUtilization,
+/// ]));
+///
+/// let markdown = report.render(&MarkdownRenderer);
+/// assert!(markdown.contains("| cpu time"));
+/// assert!(markdown.contains("| cpu utilization"));
+/// ```
+///
+/// So is the memory used by the process while the tasks measuring it ran:
+///
+/// ```
+/// use time_requirements::{prelude::*, report::MarkdownRenderer};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// let task = tracker.start_task("Load").with_memory_usage();
+/// let spectra = vec![0.0_f64; 1024 * 1024];
+/// tracker.add_completed_task(task);
+/// drop(spectra);
+/// let report = Report::from(tracker).with_options(ReportOptions::default().with_columns([
+///     Column::Name,
+///     Column::PeakMemory,
+///     Column::MemoryDelta,
+/// ]));
+///
+/// let markdown = report.render(&MarkdownRenderer);
+/// assert!(markdown.contains("| peak memory"));
+/// if cfg!(target_os = "linux") {
+///     assert!(markdown.contains(" MiB"));
+/// }
+/// ```
+pub enum Column {
+    /// The name of the task.
+    Name,
+    /// The time required by the task, including the time spent in the
+    /// sub-tracker it summarizes.
+    Time,
+    /// The time required by the task, excluding the time spent in the
+    /// sub-trackers of the sub-tracker it summarizes.
+    SelfTime,
+    /// The percentage of the time of the tracker during which the task ran,
+    /// relative to its elapsed time once finished and to the wall-clock time
+    /// covered by its tasks otherwise.
+    Percentage,
+    /// The percentage of the time of the root tracker during which the task
+    /// ran, relative to the same time as the percentages of its rows.
+    RootPercentage,
+    /// The peak number of tasks running concurrently with the task,
+    /// including itself.
+    Concurrency,
+    /// The number of items processed by the task, if counted.
+    Items,
+    /// The number of items processed per second by the task, if counted.
+    ItemsPerSecond,
+    /// The average time spent by the task on each item, if counted.
+    TimePerItem,
+    /// The number of bytes processed by the task, if counted.
+    ProcessedBytes,
+    /// The number of bytes processed per second by the task, if counted.
+    BytesPerSecond,
+    /// The time spent inside the reads and writes of the task, if it was
+    /// recorded by a timed reader or writer.
+    IoTime,
+    /// The CPU time consumed by the process while the task ran, if it was
+    /// measured.
+    CpuTime,
+    /// The CPU time consumed by the process while the task ran as a
+    /// percentage of its wall-clock time, if it was measured.
+    CpuUtilization,
+    /// The resident set size of the process when the task started, if it
+    /// was measured.
+    StartMemory,
+    /// The resident set size of the process when the task completed, if it
+    /// was measured.
+    EndMemory,
+    /// The peak resident set size of the process while the task ran, if it
+    /// was measured, preceded by `≥` when the task did not raise the peak of
+    /// the process and it is only a lower bound.
+    PeakMemory,
+    /// The change of the resident set size of the process between the start
+    /// and the end of the task, if it was measured.
+    MemoryDelta,
+    /// The number of allocations performed on the thread of the task while
+    /// it ran, if they were counted.
+    Allocations,
+    /// The number of deallocations performed on the thread of the task while
+    /// it ran, if they were counted.
+    Deallocations,
+    /// The number of bytes allocated on the thread of the task while it ran,
+    /// if they were counted.
+    AllocatedBytes,
+    /// The bytes read by the process while the task ran, if they were
+    /// measured.
+    BytesRead,
+    /// The bytes written by the process while the task ran, if they were
+    /// measured.
+    BytesWritten,
+    /// The bytes read and written per second by the process while the task
+    /// ran, if they were measured.
+    IoThroughput,
+    /// The voluntary context switches of the process while the task ran, if
+    /// they were counted.
+    VoluntaryContextSwitches,
+    /// The involuntary context switches of the process while the task ran,
+    /// if they were counted.
+    InvoluntaryContextSwitches,
+    /// The minor page faults of the process while the task ran, if they
+    /// were counted.
+    MinorPageFaults,
+    /// The major page faults of the process while the task ran, if they
+    /// were counted.
+    MajorPageFaults,
+    /// The outcome of the task, with its message if any.
+    Status,
+    /// The tags attached to the task.
+    Tags,
+    /// The free-form notes attached to the task.
+    Notes,
+    /// The value of the attribute with the provided key attached to the
+    /// task, whose header is the key.
+    Attribute(String),
+}
+
+impl Column {
+    /// Returns the header of the column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// assert_eq!(Column::SelfTime.header(), "self time");
+    /// assert_eq!(Column::Attribute("input".to_owned()).header(), "input");
+    /// ```
+    #[must_use]
+    pub fn header(&self) -> &str {
+        match self {
+            Self::Name => "name",
+            Self::Time => "time",
+            Self::SelfTime => "self time",
+            Self::Percentage => "percentage",
+            Self::RootPercentage => "percentage of root",
+            Self::Concurrency => "concurrency",
+            Self::Items => "items",
+            Self::ItemsPerSecond => "items/s",
+            Self::TimePerItem => "time per item",
+            Self::ProcessedBytes => "processed",
+            Self::BytesPerSecond => "processed/s",
+            Self::IoTime => "io time",
+            Self::CpuTime => "cpu time",
+            Self::CpuUtilization => "cpu utilization",
+            Self::StartMemory => "start memory",
+            Self::EndMemory => "end memory",
+            Self::PeakMemory => "peak memory",
+            Self::MemoryDelta => "memory delta",
+            Self::Allocations => "allocations",
+            Self::Deallocations => "deallocations",
+            Self::AllocatedBytes => "allocated bytes",
+            Self::BytesRead => "bytes read",
+            Self::BytesWritten => "bytes written",
+            Self::IoThroughput => "io throughput",
+            Self::VoluntaryContextSwitches => "voluntary switches",
+            Self::InvoluntaryContextSwitches => "involuntary switches",
+            Self::MinorPageFaults => "minor faults",
+            Self::MajorPageFaults => "major faults",
+            Self::Status => "status",
+            Self::Tags => "tags",
+            Self::Notes => "notes",
+            Self::Attribute(key) => key,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// The style used to draw the table of a report.
+pub enum TableStyle {
+    #[default]
+    /// A markdown table.
+    Markdown,
+    /// A table drawn with ASCII characters.
+    Ascii,
+    /// A table drawn with box-drawing characters.
+    Modern,
+    /// A table drawn with rounded box-drawing characters.
+    Rounded,
+    /// A table in the style of the PostgreSQL shell.
+    Psql,
+    /// A table without borders.
+    Blank,
+}
+
+impl TableStyle {
+    /// Applies the style to the provided table.
+    pub(crate) fn apply(self, table: &mut Table) {
+        match self {
+            Self::Markdown => table.with(Style::markdown()),
+            Self::Ascii => table.with(Style::ascii()),
+            Self::Modern => table.with(Style::modern()),
+            Self::Rounded => table.with(Style::rounded()),
+            Self::Psql => table.with(Style::psql()),
+            Self::Blank => table.with(Style::blank()),
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Options to customize which tasks a report shows and how.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeDelta;
+/// use time_requirements::prelude::*;
+///
+/// let options = ReportOptions::default()
+///     .with_sort_by(SortBy::Duration)
+///     .with_top(10)
+///     .with_min_percentage(1.0)
+///     .with_min_time(TimeDelta::milliseconds(5))
+///     .with_columns([Column::Name, Column::Time])
+///     .with_style(TableStyle::Ascii)
+///     .with_max_depth(2);
+///
+/// assert_eq!(options.sort_by(), SortBy::Duration);
+/// assert_eq!(options.top(), Some(10));
+/// assert_eq!(options.columns(), &[Column::Name, Column::Time]);
+/// assert_eq!(options.style(), TableStyle::Ascii);
+/// assert_eq!(options.max_depth(), Some(2));
+/// ```
+pub struct ReportOptions {
+    /// The order in which the tasks are listed.
+    sort_by: SortBy,
+    /// The maximum number of tasks listed per tracker.
+    top: Option<usize>,
+    /// The percentage of the tracker time under which tasks are hidden.
+    min_percentage: Option<f64>,
+    /// The time under which tasks are hidden.
+    min_time: Option<chrono::TimeDelta>,
+    /// The columns shown in the tables.
+    columns: Vec<Column>,
+    /// The style of the tables.
+    style: TableStyle,
+    /// The maximum depth of the sub-trackers included in the report.
+    max_depth: Option<usize>,
+    /// The number of slowest tasks across the hierarchy listed in a
+    /// dedicated section.
+    top_leaves: Option<usize>,
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        Self {
+            sort_by: SortBy::default(),
+            top: None,
+            min_percentage: None,
+            min_time: None,
+            columns: vec![
+                Column::Name,
+                Column::Time,
+                Column::SelfTime,
+                Column::Percentage,
+                Column::RootPercentage,
+            ],
+            style: TableStyle::default(),
+            max_depth: None,
+            top_leaves: None,
+        }
+    }
+}
+
+impl ReportOptions {
+    #[must_use]
+    /// Sets the order in which the tasks are listed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let options = ReportOptions::default().with_sort_by(SortBy::Name);
+    /// assert_eq!(options.sort_by(), SortBy::Name);
+    /// ```
+    pub fn with_sort_by(mut self, sort_by: SortBy) -> Self {
+        self.sort_by = sort_by;
+        self
+    }
+
+    #[must_use]
+    /// Limits the number of tasks listed per tracker to the first `top`
+    /// ones, after sorting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// for name in ["A", "B", "C"] {
+    ///     tracker.add_completed_task(Task::new(name));
+    /// }
+    ///
+    /// let options = ReportOptions::default().with_sort_by(SortBy::Name).with_top(2);
+    /// let visible: Vec<&str> =
+    ///     options.visible_tasks(&tracker).iter().map(|task| task.name()).collect();
+    /// assert_eq!(visible, ["A", "B"]);
+    /// ```
+    pub fn with_top(mut self, top: usize) -> Self {
+        self.top = Some(top);
+        self
+    }
+
+    #[must_use]
+    /// Hides the tasks requiring less than the provided percentage of the
+    /// time of their tracker, as shown in the percentage column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let options = ReportOptions::default().with_min_percentage(5.0);
+    /// assert_eq!(options.min_percentage(), Some(5.0));
+    /// ```
+    pub fn with_min_percentage(mut self, min_percentage: f64) -> Self {
+        self.min_percentage = Some(min_percentage);
+        self
+    }
+
+    #[must_use]
+    /// Hides the tasks requiring less than the provided time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use time_requirements::{clock::MockClock, prelude::*};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let task = tracker.start_task("Slow");
+    /// clock.advance(TimeDelta::milliseconds(10));
+    /// tracker.add_completed_task(task);
+    /// tracker.add_completed_task("Fast");
+    ///
+    /// let options = ReportOptions::default().with_min_time(TimeDelta::milliseconds(5));
+    /// let visible = options.visible_tasks(&tracker);
+    /// assert_eq!(visible.len(), 1);
+    /// assert_eq!(visible[0].name(), "Slow");
+    /// ```
+    pub fn with_min_time(mut self, min_time: chrono::TimeDelta) -> Self {
+        self.min_time = Some(min_time);
+        self
+    }
+
+    #[must_use]
+    /// Sets the columns shown in the tables, in the provided order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let options = ReportOptions::default().with_columns([Column::Percentage, Column::Name]);
+    /// assert_eq!(options.columns(), &[Column::Percentage, Column::Name]);
+    /// ```
+    pub fn with_columns<I: IntoIterator<Item = Column>>(mut self, columns: I) -> Self {
+        self.columns = columns.into_iter().collect();
+        self
+    }
+
+    #[must_use]
+    /// Sets the style of the tables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let options = ReportOptions::default().with_style(TableStyle::Rounded);
+    /// assert_eq!(options.style(), TableStyle::Rounded);
+    /// ```
+    pub fn with_style(mut self, style: TableStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    #[must_use]
+    /// Sets the maximum depth of the sub-trackers included in the report,
+    /// where zero only includes the root tracker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let options = ReportOptions::default().with_max_depth(0);
+    /// assert_eq!(options.max_depth(), Some(0));
+    /// ```
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    #[must_use]
+    /// Lists the provided number of slowest tasks across the whole hierarchy
+    /// in a dedicated section, excluding the tasks summarizing sub-trackers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{clock::MockClock, prelude::*};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// tracker.add_completed_task("Fast");
+    /// let mut sub_tracker = TimeTracker::with_clock("Sub Project", clock.clone());
+    /// let task = sub_tracker.start_task("Slow");
+    /// clock.advance(chrono::TimeDelta::milliseconds(10));
+    /// sub_tracker.add_completed_task(task);
+    /// tracker.extend(sub_tracker);
+    ///
+    /// let report = Report::from(tracker).with_options(ReportOptions::default().with_top_leaves(1));
+    /// let model = report.model();
+    /// assert_eq!(model.slowest_leaves.len(), 1);
+    /// assert_eq!(model.slowest_leaves[0].path, "Sub Project/Slow");
+    /// assert_eq!(model.slowest_leaves[0].depth, 1);
+    /// ```
+    pub fn with_top_leaves(mut self, top_leaves: usize) -> Self {
+        self.top_leaves = Some(top_leaves);
+        self
+    }
+
+    #[must_use]
+    /// Returns the order in which the tasks are listed.
+    pub fn sort_by(&self) -> SortBy {
+        self.sort_by
+    }
+
+    #[must_use]
+    /// Returns the maximum number of tasks listed per tracker, if any.
+    pub fn top(&self) -> Option<usize> {
+        self.top
+    }
+
+    #[must_use]
+    /// Returns the percentage under which tasks are hidden, if any.
+    pub fn min_percentage(&self) -> Option<f64> {
+        self.min_percentage
+    }
+
+    #[must_use]
+    /// Returns the time under which tasks are hidden, if any.
+    pub fn min_time(&self) -> Option<chrono::TimeDelta> {
+        self.min_time
+    }
+
+    #[must_use]
+    /// Returns the columns shown in the tables.
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    #[must_use]
+    /// Returns the style of the tables.
+    pub fn style(&self) -> TableStyle {
+        self.style
+    }
+
+    #[must_use]
+    /// Returns the maximum depth of the sub-trackers included, if any.
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    #[must_use]
+    /// Returns the number of slowest tasks across the hierarchy listed in a
+    /// dedicated section, if any.
+    pub fn top_leaves(&self) -> Option<usize> {
+        self.top_leaves
+    }
+
+    #[must_use]
+    /// Returns whether the sub-trackers at the provided depth are included.
+    pub(crate) fn includes_depth(&self, depth: usize) -> bool {
+        self.max_depth.is_none_or(|max_depth| depth <= max_depth)
+    }
+
+    #[must_use]
+    /// Returns the tasks of the provided tracker which are shown in a report,
+    /// filtered, sorted and truncated according to the options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{clock::MockClock, prelude::*};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let task = tracker.start_task("Short");
+    /// clock.advance(chrono::TimeDelta::milliseconds(10));
+    /// tracker.add_completed_task(task);
+    /// let task = tracker.start_task("Long");
+    /// clock.advance(chrono::TimeDelta::milliseconds(50));
+    /// tracker.add_completed_task(task);
+    ///
+    /// let options = ReportOptions::default().with_sort_by(SortBy::Duration);
+    /// let visible: Vec<&str> =
+    ///     options.visible_tasks(&tracker).iter().map(|task| task.name()).collect();
+    /// assert_eq!(visible, ["Long", "Short"]);
+    /// ```
+    pub fn visible_tasks<'a>(&self, tracker: &'a TimeTracker) -> Vec<&'a CompletedTask> {
+        let reference_time = reference_time(tracker);
+        let mut tasks: Vec<&CompletedTask> = tracker
+            .tasks()
+            .filter(|task| self.min_time.is_none_or(|min_time| task.time() >= min_time))
+            .filter(|task| {
+                self.min_percentage.is_none_or(|min_percentage| {
+                    percentage_of(task.time(), reference_time) >= min_percentage
+                })
+            })
+            .collect();
+
+        match self.sort_by {
+            SortBy::Insertion => {}
+            SortBy::Duration => tasks.sort_by(|a, b| b.cmp(a)),
+            SortBy::Name => tasks.sort_by(|a, b| a.name().cmp(b.name())),
+            SortBy::Start => tasks.sort_by_key(|task| task.start),
+        }
+
+        if let Some(top) = self.top {
+            tasks.truncate(top);
+        }
+
+        tasks
+    }
+}
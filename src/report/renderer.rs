@@ -0,0 +1,299 @@
+//! Submodule defining the trait to render a report model, and its
+//! implementations for the supported formats.
+
+use chrono_humanize::{Accuracy, HumanTime, Tense};
+
+use super::{Column, ReportModel, ReportOptions, ReportRow};
+use crate::{
+    memory::MemoryUsage,
+    task::{per_second, time_per_item},
+};
+
+mod html;
+mod json;
+mod markdown;
+mod plain_text;
+mod tree;
+
+pub use html::HtmlRenderer;
+pub use json::JsonRenderer;
+pub use markdown::MarkdownRenderer;
+pub use plain_text::PlainTextRenderer;
+pub use tree::TreeRenderer;
+use tree::format_duration;
+
+/// Trait for types which render a report model into a document.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{
+///     prelude::*,
+///     report::{ReportModel, Renderer},
+/// };
+///
+/// /// Renders the report as a bullet list for an internal wiki.
+/// struct WikiRenderer;
+///
+/// impl Renderer for WikiRenderer {
+///     fn render(&self, model: &ReportModel, _options: &ReportOptions) -> String {
+///         model.rows.iter().map(|row| format!("* {}\n", row.name)).collect()
+///     }
+/// }
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// tracker.add_completed_task(Task::new("Task"));
+/// let report: Report = tracker.into();
+/// assert_eq!(report.render(&WikiRenderer), "* Task\n");
+/// ```
+pub trait Renderer {
+    /// Renders the provided report model.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model of the report to render.
+    /// * `options` - The options the model was computed with, which also
+    ///   describe the columns and table style to use.
+    fn render(&self, model: &ReportModel, options: &ReportOptions) -> String;
+}
+
+/// Returns the human readable description of the provided duration.
+pub(crate) fn humanize(time: chrono::TimeDelta, accuracy: Accuracy) -> String {
+    HumanTime::from(time).to_text_en(accuracy, Tense::Present)
+}
+
+/// Formats the provided number of bytes using the largest fitting binary
+/// unit.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.2} {}", UNITS[unit])
+}
+
+/// Formats the provided signed number of bytes, always preceded by its sign.
+pub(crate) fn format_bytes_delta(bytes: i64) -> String {
+    let sign = if bytes < 0 { '-' } else { '+' };
+    format!("{sign}{}", format_bytes(bytes.unsigned_abs()))
+}
+
+/// Formats the peak of the provided memory usage, preceded by `≥` when it is
+/// only a lower bound of the actual peak.
+pub(crate) fn format_peak(memory: &MemoryUsage) -> String {
+    let bound = if memory.peak_exact { "" } else { "≥ " };
+    format!("{bound}{}", format_bytes(memory.peak_rss))
+}
+
+/// Formats the provided number of bytes per second.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) fn format_throughput(bytes_per_second: f64) -> String {
+    format!("{}/s", format_bytes(bytes_per_second.round() as u64))
+}
+
+/// Returns the content of the provided column for the provided row.
+pub(crate) fn cell(column: &Column, row: &ReportRow) -> String {
+    match column {
+        Column::Name => row.name.clone(),
+        Column::Time => humanize(row.time, Accuracy::Precise),
+        Column::SelfTime => humanize(row.self_time, Accuracy::Precise),
+        Column::Percentage => format!("{:.2}%", row.percentage),
+        Column::RootPercentage => format!("{:.2}%", row.root_percentage),
+        Column::Concurrency => row.concurrency.to_string(),
+        Column::Items => row.items.map(|items| items.to_string()).unwrap_or_default(),
+        Column::ItemsPerSecond => {
+            row.items.map(|items| format!("{:.2}", per_second(items, row.time))).unwrap_or_default()
+        }
+        Column::TimePerItem => row
+            .items
+            .and_then(|items| time_per_item(items, row.time))
+            .map(format_duration)
+            .unwrap_or_default(),
+        Column::ProcessedBytes => row.processed_bytes.map(format_bytes).unwrap_or_default(),
+        Column::BytesPerSecond => row
+            .processed_bytes
+            .map(|bytes| format_throughput(per_second(bytes, row.time)))
+            .unwrap_or_default(),
+        Column::IoTime => {
+            row.io_time.map(|time| humanize(time, Accuracy::Precise)).unwrap_or_default()
+        }
+        Column::CpuTime => {
+            row.cpu.map(|cpu| humanize(cpu.process(), Accuracy::Precise)).unwrap_or_default()
+        }
+        Column::CpuUtilization => {
+            row.cpu.map(|cpu| format!("{:.2}%", cpu.utilization(row.time))).unwrap_or_default()
+        }
+        Column::StartMemory => {
+            row.memory.and_then(|memory| memory.start_rss).map(format_bytes).unwrap_or_default()
+        }
+        Column::EndMemory => {
+            row.memory.and_then(|memory| memory.end_rss).map(format_bytes).unwrap_or_default()
+        }
+        Column::PeakMemory => row.memory.map(|memory| format_peak(&memory)).unwrap_or_default(),
+        Column::MemoryDelta => {
+            row.memory.and_then(|memory| memory.delta()).map(format_bytes_delta).unwrap_or_default()
+        }
+        Column::Allocations => {
+            row.allocations.map(|counts| counts.allocations.to_string()).unwrap_or_default()
+        }
+        Column::Deallocations => {
+            row.allocations.map(|counts| counts.deallocations.to_string()).unwrap_or_default()
+        }
+        Column::AllocatedBytes => {
+            row.allocations.map(|counts| format_bytes(counts.allocated_bytes)).unwrap_or_default()
+        }
+        Column::BytesRead => row.io.map(|io| format_bytes(io.read_bytes)).unwrap_or_default(),
+        Column::BytesWritten => row.io.map(|io| format_bytes(io.written_bytes)).unwrap_or_default(),
+        Column::IoThroughput => {
+            row.io.map(|io| format_throughput(io.throughput(row.time))).unwrap_or_default()
+        }
+        Column::VoluntaryContextSwitches => row
+            .scheduling
+            .map(|events| events.voluntary_context_switches.to_string())
+            .unwrap_or_default(),
+        Column::InvoluntaryContextSwitches => row
+            .scheduling
+            .map(|events| events.involuntary_context_switches.to_string())
+            .unwrap_or_default(),
+        Column::MinorPageFaults => {
+            row.scheduling.map(|events| events.minor_page_faults.to_string()).unwrap_or_default()
+        }
+        Column::MajorPageFaults => {
+            row.scheduling.map(|events| events.major_page_faults.to_string()).unwrap_or_default()
+        }
+        Column::Status => row.outcome.to_string(),
+        Column::Tags => row.metadata.tags().collect::<Vec<_>>().join(", "),
+        Column::Notes => row.metadata.notes().join("; "),
+        Column::Attribute(key) => row.metadata.attribute(key).unwrap_or_default().to_owned(),
+    }
+}
+
+/// Returns the sentence describing the total time of the provided model.
+pub(crate) fn description(model: &ReportModel) -> String {
+    let total_time = format!(
+        "The total time spent on all tasks was {}.",
+        humanize(model.total_time, Accuracy::Rough)
+    );
+    let mut sentences = vec![total_time];
+    if model.self_time != model.total_time {
+        sentences.push(format!(
+            "Of this, {} was spent on its own tasks and {} in its sub-trackers.",
+            humanize(model.self_time, Accuracy::Precise),
+            humanize(model.total_time - model.self_time, Accuracy::Precise),
+        ));
+    }
+    if model.tracked_time != model.total_time {
+        sentences.push(format!(
+            "As some tasks ran concurrently, they covered {} of wall-clock time, \
+             with a parallelism factor of {:.2}.",
+            humanize(model.tracked_time, Accuracy::Precise),
+            model.parallelism,
+        ));
+    }
+    if !model.outcome_counts.all_succeeded() {
+        sentences.push(outcome_description(model));
+    }
+    if let Some(untracked) = &model.untracked {
+        sentences.push(format!(
+            "Out of {} elapsed, {} ({:.2}%) were not covered by any task.",
+            humanize(model.elapsed, Accuracy::Precise),
+            humanize(untracked.time, Accuracy::Precise),
+            untracked.percentage,
+        ));
+    }
+    sentences.join("\n")
+}
+
+/// Returns the sentence describing the number of tasks with each outcome of
+/// the provided model, and the time spent on the successful and failed ones.
+fn outcome_description(model: &ReportModel) -> String {
+    let counts = model.outcome_counts;
+    let parts: Vec<String> = [
+        (counts.success, "succeeded"),
+        (counts.failure, "failed"),
+        (counts.panicked, "panicked"),
+        (counts.cancelled, "were cancelled"),
+        (counts.skipped, "were skipped"),
+    ]
+    .into_iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, verb)| format!("{count} {verb}"))
+    .collect();
+    let parts = match parts.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+        _ => parts.join(""),
+    };
+    format!(
+        "Out of {} tasks, {parts}, with {} spent on successful tasks and {} on failed ones.",
+        counts.total(),
+        humanize(model.successful_time, Accuracy::Precise),
+        humanize(model.failed_time, Accuracy::Precise),
+    )
+}
+
+/// Returns the sentence describing the slowest task of the provided model,
+/// if any.
+pub(crate) fn slowest_task_description(model: &ReportModel) -> Option<String> {
+    model.slowest_task.as_ref().map(|task| {
+        format!(
+            "The slowest task was `{}` which took {} ({:.2}% of all time).",
+            task.name,
+            humanize(task.time, Accuracy::Precise),
+            task.percentage,
+        )
+    })
+}
+
+/// Returns the table of the provided model as drawn with the configured
+/// style.
+pub(crate) fn table(model: &ReportModel, options: &ReportOptions) -> String {
+    let mut builder = tabled::builder::Builder::default();
+    builder.push_record(options.columns().iter().map(Column::header));
+    for row in model.rows.iter().chain(&model.untracked) {
+        builder.push_record(options.columns().iter().map(|column| cell(column, row)));
+    }
+    let mut table = builder.build();
+    options.style().apply(&mut table);
+    table.to_string()
+}
+
+/// The title of the section listing the slowest tasks across the
+/// hierarchy.
+pub(crate) const SLOWEST_LEAVES_TITLE: &str = "Slowest tasks across the hierarchy";
+
+/// Returns the cells of the table of the slowest tasks across the hierarchy,
+/// starting from the header.
+pub(crate) fn leaves_records(model: &ReportModel) -> Vec<[String; 3]> {
+    std::iter::once([
+        "path".to_owned(),
+        "time".to_owned(),
+        Column::RootPercentage.header().to_owned(),
+    ])
+    .chain(model.slowest_leaves.iter().map(|leaf| {
+        [
+            leaf.path.clone(),
+            humanize(leaf.time, Accuracy::Precise),
+            format!("{:.2}%", leaf.root_percentage),
+        ]
+    }))
+    .collect()
+}
+
+/// Returns the table of the slowest tasks across the hierarchy as drawn with
+/// the configured style.
+pub(crate) fn leaves_table(model: &ReportModel, options: &ReportOptions) -> String {
+    let mut builder = tabled::builder::Builder::default();
+    for record in leaves_records(model) {
+        builder.push_record(record);
+    }
+    let mut table = builder.build();
+    options.style().apply(&mut table);
+    table.to_string()
+}
@@ -0,0 +1,122 @@
+//! Submodule defining the HTML renderer.
+
+use super::{
+    Renderer, SLOWEST_LEAVES_TITLE, cell, description, leaves_records, slowest_task_description,
+};
+use crate::report::{ReportModel, ReportOptions};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// Renders a report as an HTML fragment, with a section per tracker.
+///
+/// # Implementation Note
+///
+/// The table style of the report options is ignored, as the appearance of
+/// the tables is left to the stylesheet of the embedding page.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{prelude::*, report::HtmlRenderer};
+///
+/// let mut tracker = TimeTracker::new("Project <1>");
+/// tracker.add_completed_task(Task::new("Task"));
+/// let report: Report = tracker.into();
+///
+/// let html = report.render(&HtmlRenderer);
+/// assert!(html.starts_with("<section>\n<h1>Time Report for Project &lt;1&gt;</h1>\n"));
+/// assert!(html.contains("<th>name</th>"));
+/// assert!(html.contains("<td>Task</td>"));
+///
+/// // The headers of the attribute columns are escaped as well.
+/// let report = report.with_options(
+///     ReportOptions::default().with_columns([Column::Name, Column::Attribute("<b>key</b>".into())]),
+/// );
+/// assert!(report.render(&HtmlRenderer).contains("<th>&lt;b&gt;key&lt;/b&gt;</th>"));
+/// ```
+pub struct HtmlRenderer;
+
+/// Returns the provided content, already escaped, wrapped in the provided tag.
+fn element(tag: &str, content: &str) -> String {
+    format!("<{tag}>{content}</{tag}>")
+}
+
+/// Escapes the characters with a special meaning in HTML.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+impl HtmlRenderer {
+    /// Renders the provided model as a section at the provided depth.
+    fn section(model: &ReportModel, options: &ReportOptions, depth: usize) -> String {
+        let level = (depth + 1).min(6);
+        let mut report = format!(
+            "<section>\n<h{level}>Time Report for {}</h{level}>\n<p>{}",
+            escape(&model.name),
+            escape(&description(model))
+        );
+
+        if let Some(description) = slowest_task_description(model) {
+            report.push_str("<br>\n");
+            report.push_str(&escape(&description));
+        }
+        report.push_str("</p>\n<table>\n<thead>\n<tr>");
+
+        for column in options.columns() {
+            report.push_str(&element("th", &escape(column.header())));
+        }
+        report.push_str("</tr>\n</thead>\n<tbody>\n");
+
+        for row in model.rows.iter().chain(&model.untracked) {
+            if row.outcome.is_success() {
+                report.push_str("<tr>");
+            } else {
+                report.push_str("<tr class=\"");
+                report.push_str(row.outcome.status());
+                report.push_str("\">");
+            }
+            for column in options.columns() {
+                report.push_str(&element("td", &escape(&cell(column, row))));
+            }
+            report.push_str("</tr>\n");
+        }
+        report.push_str("</tbody>\n</table>\n");
+
+        if !model.slowest_leaves.is_empty() {
+            report.push_str(&element(&format!("h{}", (depth + 2).min(6)), SLOWEST_LEAVES_TITLE));
+            report.push_str("\n<table>\n");
+            for (index, record) in leaves_records(model).into_iter().enumerate() {
+                let tag = if index == 0 { "th" } else { "td" };
+                report.push_str("<tr>");
+                for value in record {
+                    report.push_str(&element(tag, &escape(&value)));
+                }
+                report.push_str("</tr>\n");
+            }
+            report.push_str("</table>\n");
+        }
+
+        for child in &model.children {
+            report.push_str(&Self::section(child, options, depth + 1));
+        }
+
+        report.push_str("</section>\n");
+        report
+    }
+}
+
+impl Renderer for HtmlRenderer {
+    fn render(&self, model: &ReportModel, options: &ReportOptions) -> String {
+        Self::section(model, options, 0)
+    }
+}
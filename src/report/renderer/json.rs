@@ -0,0 +1,32 @@
+//! Submodule defining the JSON renderer.
+
+use super::Renderer;
+use crate::report::{ReportModel, ReportOptions};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// Renders a report as the pretty-printed JSON of its model.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{
+///     prelude::*,
+///     report::{JsonRenderer, ReportModel},
+/// };
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// tracker.add_completed_task(Task::new("Task"));
+/// let report: Report = tracker.into();
+///
+/// let json = report.render(&JsonRenderer);
+/// let model: ReportModel = serde_json::from_str(&json).unwrap();
+/// assert_eq!(model.name, "Project");
+/// assert_eq!(model.rows[0].name, "Task");
+/// ```
+pub struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+    fn render(&self, model: &ReportModel, _options: &ReportOptions) -> String {
+        serde_json::to_string_pretty(model).expect("A report model is always serializable")
+    }
+}
@@ -0,0 +1,65 @@
+//! Submodule defining the markdown renderer.
+
+use super::{
+    Renderer, SLOWEST_LEAVES_TITLE, description, leaves_table, slowest_task_description, table,
+};
+use crate::report::{ReportModel, ReportOptions};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// Renders a report as a markdown document, with a section per tracker.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{prelude::*, report::MarkdownRenderer};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// tracker.add_completed_task(Task::new("Task"));
+/// tracker.extend(TimeTracker::new("Sub Project"));
+/// let report: Report = tracker.into();
+///
+/// let markdown = report.render(&MarkdownRenderer);
+/// assert!(markdown.starts_with("# Time Report for Project\n"));
+/// assert!(markdown.contains("\n## Time Report for Sub Project\n"));
+/// assert!(markdown.contains("| name "));
+/// ```
+pub struct MarkdownRenderer;
+
+impl MarkdownRenderer {
+    /// Renders the provided model as a section at the provided depth.
+    fn section(model: &ReportModel, options: &ReportOptions, depth: usize) -> String {
+        let mut report = format!("{} Time Report for {}\n\n", "#".repeat(depth + 1), model.name);
+
+        report.push_str(&description(model));
+        report.push('\n');
+
+        if let Some(description) = slowest_task_description(model) {
+            report.push_str(&description);
+        }
+
+        report.push_str("\n\n");
+        report.push_str(&table(model, options));
+
+        if !model.slowest_leaves.is_empty() {
+            report.push_str("\n\n");
+            report.push_str(&"#".repeat((depth + 2).min(6)));
+            report.push(' ');
+            report.push_str(SLOWEST_LEAVES_TITLE);
+            report.push_str("\n\n");
+            report.push_str(&leaves_table(model, options));
+        }
+
+        for child in &model.children {
+            report.push_str("\n\n");
+            report.push_str(&Self::section(child, options, (depth + 1).min(5)));
+        }
+
+        report
+    }
+}
+
+impl Renderer for MarkdownRenderer {
+    fn render(&self, model: &ReportModel, options: &ReportOptions) -> String {
+        Self::section(model, options, 0)
+    }
+}
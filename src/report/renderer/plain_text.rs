@@ -0,0 +1,73 @@
+//! Submodule defining the plain text renderer.
+
+use super::{
+    Renderer, SLOWEST_LEAVES_TITLE, description, leaves_table, slowest_task_description, table,
+};
+use crate::report::{ReportModel, ReportOptions};
+
+/// Characters used to underline the titles, from the outermost level.
+const UNDERLINES: [char; 3] = ['=', '-', '~'];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// Renders a report as a plain text document, with underlined titles.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{prelude::*, report::PlainTextRenderer};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// tracker.add_completed_task(Task::new("Task"));
+/// let report: Report =
+///     Report::from(tracker).with_options(ReportOptions::default().with_style(TableStyle::Ascii));
+///
+/// let text = report.render(&PlainTextRenderer);
+/// assert!(text.starts_with("Time Report for Project\n=======================\n"));
+/// assert!(text.contains("+------"));
+/// ```
+pub struct PlainTextRenderer;
+
+impl PlainTextRenderer {
+    /// Returns the provided title underlined according to the provided depth.
+    fn title(title: &str, depth: usize) -> String {
+        let underline = UNDERLINES[depth.min(UNDERLINES.len() - 1)];
+        format!(
+            "{title}\n{}\n\n",
+            std::iter::repeat_n(underline, title.chars().count()).collect::<String>()
+        )
+    }
+
+    /// Renders the provided model as a section at the provided depth.
+    fn section(model: &ReportModel, options: &ReportOptions, depth: usize) -> String {
+        let mut report = Self::title(&format!("Time Report for {}", model.name), depth);
+
+        report.push_str(&description(model));
+        report.push('\n');
+
+        if let Some(description) = slowest_task_description(model) {
+            report.push_str(&description);
+        }
+
+        report.push_str("\n\n");
+        report.push_str(&table(model, options));
+
+        if !model.slowest_leaves.is_empty() {
+            report.push_str("\n\n");
+            report.push_str(&Self::title(SLOWEST_LEAVES_TITLE, depth + 1));
+            report.push_str(&leaves_table(model, options));
+        }
+
+        for child in &model.children {
+            report.push_str("\n\n");
+            report.push_str(&Self::section(child, options, depth + 1));
+        }
+
+        report
+    }
+}
+
+impl Renderer for PlainTextRenderer {
+    fn render(&self, model: &ReportModel, options: &ReportOptions) -> String {
+        Self::section(model, options, 0)
+    }
+}
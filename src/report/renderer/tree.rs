@@ -0,0 +1,227 @@
+//! Submodule defining the renderer of the hierarchy as an indented tree.
+
+use super::{Renderer, format_bytes, format_bytes_delta, format_peak, format_throughput};
+use crate::{
+    report::{ReportModel, ReportOptions, ReportRow},
+    task::per_second,
+};
+
+/// Width in characters of the percentage bars drawn in the tree view.
+const BAR_WIDTH: usize = 20;
+/// Share of the parent time above which an entry of the tree is highlighted.
+const HIGHLIGHT_PERCENTAGE: f64 = 25.0;
+/// ANSI escape sequence used for the name of the root tracker.
+const BOLD: &str = "\x1b[1m";
+/// ANSI escape sequence used for the slowest entry of each level.
+const SLOWEST_COLOR: &str = "\x1b[1;31m";
+/// ANSI escape sequence used for the entries above the highlight threshold.
+const HIGHLIGHT_COLOR: &str = "\x1b[33m";
+/// ANSI escape sequence used for the outcome of the tasks which did not
+/// succeed.
+const FAILURE_COLOR: &str = "\x1b[31m";
+/// ANSI escape sequence used for the tree decorations.
+const DIM_COLOR: &str = "\x1b[2m";
+/// ANSI escape sequence resetting the colors.
+const RESET_COLOR: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// Renders the hierarchy of a report as an indented tree drawn with
+/// box-drawing characters, with durations and percentage bars.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{prelude::*, report::TreeRenderer};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// tracker.add_completed_task(Task::new("Task"));
+/// let report: Report = tracker.into();
+///
+/// let tree = report.render(&TreeRenderer { colored: false });
+/// assert!(tree.starts_with("Project"));
+/// assert!(tree.contains("└── Task"));
+/// ```
+pub struct TreeRenderer {
+    /// Whether to color the slowest entries with ANSI escape sequences.
+    pub colored: bool,
+}
+
+impl TreeRenderer {
+    /// Wraps the provided text in the provided ANSI color, if requested.
+    fn paint(self, text: &str, color: &str) -> String {
+        if self.colored && !text.is_empty() {
+            format!("{color}{text}{RESET_COLOR}")
+        } else {
+            text.to_owned()
+        }
+    }
+
+    /// Returns the color of the provided row, if it should be highlighted.
+    fn color_of(model: &ReportModel, row: &ReportRow) -> Option<&'static str> {
+        if model.slowest_task.as_ref().is_some_and(|slowest| {
+            slowest.name == row.name && slowest.start == row.start && slowest.end == row.end
+        }) {
+            Some(SLOWEST_COLOR)
+        } else if row.percentage >= HIGHLIGHT_PERCENTAGE {
+            Some(HIGHLIGHT_COLOR)
+        } else {
+            None
+        }
+    }
+
+    /// Appends to the line of the provided row the throughput and resource
+    /// usage it measured, if any.
+    fn push_measurements(self, row: &ReportRow, tree: &mut String) {
+        if let Some(items) = row.items {
+            tree.push_str(&self.paint(
+                &format!("  {items} items ({:.2}/s)", per_second(items, row.time)),
+                DIM_COLOR,
+            ));
+        }
+        if let Some(bytes) = row.processed_bytes {
+            tree.push_str(&self.paint(
+                &format!(
+                    "  {} ({})",
+                    format_bytes(bytes),
+                    format_throughput(per_second(bytes, row.time))
+                ),
+                DIM_COLOR,
+            ));
+        }
+        if let Some(io_time) = row.io_time {
+            tree.push_str(
+                &self.paint(&format!("  io time {}", format_duration(io_time)), DIM_COLOR),
+            );
+        }
+        if let Some(cpu) = row.cpu {
+            tree.push_str(&self.paint(
+                &format!(
+                    "  cpu {} ({:.2}%)",
+                    format_duration(cpu.process()),
+                    cpu.utilization(row.time)
+                ),
+                DIM_COLOR,
+            ));
+        }
+        if let Some(memory) = row.memory {
+            let delta = memory
+                .delta()
+                .map(|delta| format!(" ({})", format_bytes_delta(delta)))
+                .unwrap_or_default();
+            tree.push_str(
+                &self.paint(&format!("  peak {}{delta}", format_peak(&memory)), DIM_COLOR),
+            );
+        }
+        if let Some(allocations) = row.allocations {
+            tree.push_str(&self.paint(
+                &format!(
+                    "  allocs {} ({})",
+                    allocations.allocations,
+                    format_bytes(allocations.allocated_bytes)
+                ),
+                DIM_COLOR,
+            ));
+        }
+        if let Some(io) = row.io {
+            tree.push_str(&self.paint(
+                &format!(
+                    "  io {} read, {} written ({})",
+                    format_bytes(io.read_bytes),
+                    format_bytes(io.written_bytes),
+                    format_throughput(io.throughput(row.time))
+                ),
+                DIM_COLOR,
+            ));
+        }
+        if let Some(events) = row.scheduling {
+            tree.push_str(&self.paint(
+                &format!(
+                    "  switches {}+{} faults {}+{}",
+                    events.voluntary_context_switches,
+                    events.involuntary_context_switches,
+                    events.minor_page_faults,
+                    events.major_page_faults
+                ),
+                DIM_COLOR,
+            ));
+        }
+    }
+
+    /// Appends to the tree the lines describing the rows of the provided
+    /// model, recursing into its children.
+    fn push_children(self, model: &ReportModel, prefix: &str, tree: &mut String) {
+        let rows = model.rows_with_children().chain(model.untracked.iter().map(|row| (row, None)));
+        let number_of_rows = model.rows.len() + usize::from(model.untracked.is_some());
+
+        for (index, (row, child)) in rows.enumerate() {
+            let is_last = index + 1 == number_of_rows;
+            let color = Self::color_of(model, row);
+            let paint_entry = |text: &str| match color {
+                Some(color) => self.paint(text, color),
+                None => text.to_owned(),
+            };
+
+            tree.push_str(&self.paint(prefix, DIM_COLOR));
+            tree.push_str(&self.paint(if is_last { "└── " } else { "├── " }, DIM_COLOR));
+            tree.push_str(&paint_entry(&row.name));
+            tree.push_str("  ");
+            tree.push_str(&paint_entry(&format_duration(row.time)));
+            tree.push_str("  ");
+            tree.push_str(&self.paint(&percentage_bar(row.percentage), DIM_COLOR));
+            tree.push(' ');
+            tree.push_str(&paint_entry(&format!("{:.2}%", row.percentage)));
+            self.push_measurements(row, tree);
+            if row.concurrency > 1 {
+                tree.push_str(&self.paint(&format!("  ×{}", row.concurrency), DIM_COLOR));
+            }
+            if !row.outcome.is_success() {
+                tree.push_str("  ");
+                tree.push_str(&self.paint(&format!("[{}]", row.outcome), FAILURE_COLOR));
+            }
+            tree.push('\n');
+
+            if let Some(child) = child {
+                let child_prefix = format!("{prefix}{}", if is_last { "    " } else { "│   " });
+                self.push_children(child, &child_prefix, tree);
+            }
+        }
+    }
+}
+
+impl Renderer for TreeRenderer {
+    fn render(&self, model: &ReportModel, _options: &ReportOptions) -> String {
+        let mut tree =
+            format!("{}  {}\n", self.paint(&model.name, BOLD), format_duration(model.total_time));
+        self.push_children(model, "", &mut tree);
+        tree
+    }
+}
+
+/// Returns a bar of box-drawing characters filled proportionally to the
+/// provided percentage.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn percentage_bar(percentage: f64) -> String {
+    let filled = ((percentage.clamp(0.0, 100.0) / 100.0) * BAR_WIDTH as f64).round() as usize;
+    format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled))
+}
+
+/// Formats the provided duration compactly, using the largest fitting unit.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn format_duration(duration: chrono::TimeDelta) -> String {
+    let Some(nanoseconds) = duration.num_nanoseconds() else {
+        return format!("{} s", duration.num_seconds());
+    };
+    let nanoseconds = nanoseconds as f64;
+    if nanoseconds >= 60e9 {
+        let seconds = duration.num_seconds();
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    } else if nanoseconds >= 1e9 {
+        format!("{:.2} s", nanoseconds / 1e9)
+    } else if nanoseconds >= 1e6 {
+        format!("{:.2} ms", nanoseconds / 1e6)
+    } else if nanoseconds >= 1e3 {
+        format!("{:.2} µs", nanoseconds / 1e3)
+    } else {
+        format!("{nanoseconds} ns")
+    }
+}
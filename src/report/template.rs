@@ -0,0 +1,350 @@
+//! Submodule defining the context passed to user-supplied template engines,
+//! and the trait to plug such engines into a report.
+//!
+//! The crate does not depend on any template engine: any engine accepting a
+//! serializable context, such as `minijinja` or `tera`, can be plugged in by
+//! implementing [`TemplateEngine`] or by passing a closure. For instance,
+//! with `minijinja`:
+//!
+//! ```ignore
+//! let mut environment = minijinja::Environment::new();
+//! environment.add_template("report", "{{ name }} took {{ total_time.human }}")?;
+//! let template = environment.get_template("report")?;
+//! let text = report.render_template(&|context: &TemplateContext| template.render(context))?;
+//! ```
+
+use std::collections::BTreeMap;
+
+use chrono_humanize::Accuracy;
+
+use super::{ReportLeaf, ReportModel, ReportRow, renderer::humanize};
+use crate::{
+    allocations::AllocationCounts,
+    io::IoCounters,
+    memory::MemoryUsage,
+    outcome::OutcomeCounts,
+    scheduling::SchedulingEvents,
+    task::{per_second, time_per_item},
+};
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// A duration as exposed to the templates.
+pub struct TemplateDuration {
+    /// The duration in nanoseconds.
+    pub nanoseconds: i64,
+    /// The duration in seconds.
+    pub seconds: f64,
+    /// The precise human readable description of the duration, such as
+    /// `1 s and 200 ms`.
+    pub human: String,
+}
+
+impl From<chrono::TimeDelta> for TemplateDuration {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(time: chrono::TimeDelta) -> Self {
+        let nanoseconds = time.num_nanoseconds().unwrap_or(i64::MAX);
+        Self {
+            nanoseconds,
+            seconds: nanoseconds as f64 / 1e9,
+            human: humanize(time, Accuracy::Precise),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// A task as exposed to the templates.
+pub struct TemplateTask {
+    /// The name of the task.
+    pub name: String,
+    /// The start time of the task, formatted as an ISO 8601 string.
+    pub start: chrono::NaiveDateTime,
+    /// The end time of the task, formatted as an ISO 8601 string.
+    pub end: chrono::NaiveDateTime,
+    /// The time required by the task, including its sub-tracker.
+    pub time: TemplateDuration,
+    /// The time required by the task, excluding the sub-trackers of its
+    /// sub-tracker.
+    pub self_time: TemplateDuration,
+    /// The percentage of the time of the tracker during which the task ran,
+    /// relative to its elapsed time once finished, so that the tasks add up
+    /// with the untracked time, and to the wall-clock time covered by its
+    /// tasks otherwise.
+    pub percentage: f64,
+    /// The percentage of the time of the root tracker during which the task
+    /// ran, relative to the same time as the percentages of its tasks.
+    pub root_percentage: f64,
+    /// The peak number of tasks running concurrently with the task,
+    /// including itself.
+    pub concurrency: usize,
+    /// Whether the task summarizes a sub-tracker, described in `children`.
+    pub is_sub_tracker: bool,
+    /// The status of the outcome of the task, such as `success` or
+    /// `failure`.
+    pub status: String,
+    /// The message explaining the outcome of the task, if any.
+    pub message: Option<String>,
+    /// The tags attached to the task, in alphabetical order.
+    pub tags: Vec<String>,
+    /// The key-value attributes attached to the task.
+    pub attributes: BTreeMap<String, String>,
+    /// The free-form notes attached to the task.
+    pub notes: Vec<String>,
+    /// The number of items processed by the task, if counted.
+    pub items: Option<u64>,
+    /// The number of items processed per second by the task, if counted.
+    pub items_per_second: Option<f64>,
+    /// The average time spent by the task on each item, if counted.
+    pub time_per_item: Option<TemplateDuration>,
+    /// The number of bytes processed by the task, if counted.
+    pub processed_bytes: Option<u64>,
+    /// The number of bytes processed per second by the task, if counted.
+    pub bytes_per_second: Option<f64>,
+    /// The time spent inside the reads and writes of the task, if it was
+    /// recorded.
+    pub io_time: Option<TemplateDuration>,
+    /// The CPU time consumed by the process while the task ran, if it was
+    /// measured.
+    pub cpu_time: Option<TemplateDuration>,
+    /// The CPU time consumed by the process while the task ran as a
+    /// percentage of its wall-clock time, if it was measured.
+    pub cpu_utilization: Option<f64>,
+    /// The memory used by the process while the task ran, in bytes, if it
+    /// was measured.
+    pub memory: Option<MemoryUsage>,
+    /// The allocations performed on the thread of the task while it ran, if
+    /// they were counted.
+    pub allocations: Option<AllocationCounts>,
+    /// The I/O performed by the process while the task ran, if it was
+    /// measured.
+    pub io: Option<IoCounters>,
+    /// The bytes read and written per second by the process while the task
+    /// ran, if they were measured.
+    pub io_throughput: Option<f64>,
+    /// The context switches and page faults of the process while the task
+    /// ran, if they were counted.
+    pub scheduling: Option<SchedulingEvents>,
+}
+
+impl From<&ReportRow> for TemplateTask {
+    fn from(row: &ReportRow) -> Self {
+        Self {
+            name: row.name.clone(),
+            start: row.start,
+            end: row.end,
+            time: row.time.into(),
+            self_time: row.self_time.into(),
+            percentage: row.percentage,
+            root_percentage: row.root_percentage,
+            concurrency: row.concurrency,
+            is_sub_tracker: row.is_sub_tracker,
+            status: row.outcome.status().to_owned(),
+            message: row.outcome.message().map(str::to_owned),
+            tags: row.metadata.tags().map(str::to_owned).collect(),
+            attributes: row
+                .metadata
+                .attributes()
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .collect(),
+            notes: row.metadata.notes().to_vec(),
+            items: row.items,
+            items_per_second: row.items.map(|items| per_second(items, row.time)),
+            time_per_item: row
+                .items
+                .and_then(|items| time_per_item(items, row.time))
+                .map(TemplateDuration::from),
+            processed_bytes: row.processed_bytes,
+            bytes_per_second: row.processed_bytes.map(|bytes| per_second(bytes, row.time)),
+            io_time: row.io_time.map(TemplateDuration::from),
+            cpu_time: row.cpu.map(|cpu| cpu.process().into()),
+            cpu_utilization: row.cpu.map(|cpu| cpu.utilization(row.time)),
+            memory: row.memory,
+            allocations: row.allocations,
+            io: row.io,
+            io_throughput: row.io.map(|io| io.throughput(row.time)),
+            scheduling: row.scheduling,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// One of the slowest tasks across the hierarchy, as exposed to the
+/// templates.
+pub struct TemplateLeaf {
+    /// The slash-separated path of the task, relative to the root tracker.
+    pub path: String,
+    /// The depth of the tracker containing the task, zero for the root.
+    pub depth: usize,
+    /// The time required by the task.
+    pub time: TemplateDuration,
+    /// The percentage of the time of the root tracker during which the task
+    /// ran, relative to its elapsed time once finished and to the wall-clock
+    /// time covered by its tasks otherwise.
+    pub root_percentage: f64,
+}
+
+impl From<&ReportLeaf> for TemplateLeaf {
+    fn from(leaf: &ReportLeaf) -> Self {
+        Self {
+            path: leaf.path.clone(),
+            depth: leaf.depth,
+            time: leaf.time.into(),
+            root_percentage: leaf.root_percentage,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// The context passed to the templates, describing a tracker.
+///
+/// Serialized, the context has the following shape, where `children` holds
+/// the contexts of the sub-trackers recursively and the measurements of a
+/// task are `null` unless they were counted or measured:
+///
+/// ```text
+/// {
+///   "name": "My Project",
+///   "start": "2025-01-01T12:00:00",
+///   "total_time": { "nanoseconds": 1200000000, "seconds": 1.2, "human": "1 s and 200 ms" },
+///   "self_time": <same shape as total_time>,
+///   "tracked_time": <same shape as total_time>,
+///   "parallelism": 1.0,
+///   "elapsed": <same shape as total_time>,
+///   "outcome_counts": { "success": 3, "failure": 1, "panicked": 0, "cancelled": 0, "skipped": 0 },
+///   "successful_time": <same shape as total_time>,
+///   "failed_time": <same shape as total_time>,
+///   "untracked": <same shape as slowest_task, or null>,
+///   "slowest_task": {
+///     "name": ..., "start": ..., "end": ..., "time": ..., "self_time": ...,
+///     "percentage": ..., "root_percentage": ..., "concurrency": ..., "is_sub_tracker": ...,
+///     "status": "success", "message": null, "tags": [...], "attributes": { ... }, "notes": [...],
+///     "items": 1000, "items_per_second": 500.0, "time_per_item": <same shape as total_time>,
+///     "processed_bytes": 4096, "bytes_per_second": 2048.0, "io_time": <same shape as total_time>,
+///     "cpu_time": <same shape as total_time>, "cpu_utilization": 95.0,
+///     "memory": { "start_rss": 1024, "end_rss": 2048, "peak_rss": 4096, "peak_exact": true },
+///     "allocations": { "allocations": 10, "reallocations": 2, "deallocations": 8, "allocated_bytes": 640, "deallocated_bytes": 512 },
+///     "io": { "read_bytes": 4096, "written_bytes": 0, "read_syscalls": 1, "write_syscalls": 0, "storage_read_bytes": 0, "storage_written_bytes": 0 },
+///     "io_throughput": 2048.0,
+///     "scheduling": { "voluntary_context_switches": 3, "involuntary_context_switches": 1, "minor_page_faults": 20, "major_page_faults": 0 }
+///   },
+///   "tasks": [ <same shape as slowest_task>, ... ],
+///   "children": [ <same shape as the context>, ... ],
+///   "slowest_leaves": [ { "path": "Sub Project/Task", "depth": 1, "time": ..., "root_percentage": ... }, ... ]
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{prelude::*, report::TemplateContext};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// tracker.add_completed_task(Task::new("Task"));
+/// tracker.extend(TimeTracker::new("Sub Project"));
+/// let report: Report = tracker.into();
+///
+/// let context = TemplateContext::from(&report.model());
+/// assert_eq!(context.name, "Project");
+/// assert_eq!(context.tasks.len(), 2);
+/// assert_eq!(context.children[0].name, "Sub Project");
+/// ```
+pub struct TemplateContext {
+    /// The name of the tracker.
+    pub name: String,
+    /// The start time of the tracker, formatted as an ISO 8601 string.
+    pub start: chrono::NaiveDateTime,
+    /// The total time spent on all tasks.
+    pub total_time: TemplateDuration,
+    /// The time spent on the tasks of the tracker itself, excluding its
+    /// sub-trackers.
+    pub self_time: TemplateDuration,
+    /// The wall-clock time covered by at least one task.
+    pub tracked_time: TemplateDuration,
+    /// The total time divided by the tracked time, which is one when no
+    /// tasks ran concurrently.
+    pub parallelism: f64,
+    /// The wall-clock time elapsed between the start and the end of the
+    /// tracker.
+    pub elapsed: TemplateDuration,
+    /// The number of tasks with each outcome across the hierarchy.
+    pub outcome_counts: OutcomeCounts,
+    /// The time spent on the successful tasks across the hierarchy.
+    pub successful_time: TemplateDuration,
+    /// The time spent on the failed tasks across the hierarchy.
+    pub failed_time: TemplateDuration,
+    /// The time not covered by any task, if the tracker was finished, whose
+    /// percentage is relative to the elapsed time.
+    pub untracked: Option<TemplateTask>,
+    /// The slowest task of the tracker, if any.
+    pub slowest_task: Option<TemplateTask>,
+    /// The tasks shown in the report, according to the report options.
+    pub tasks: Vec<TemplateTask>,
+    /// The contexts of the sub-trackers shown in the report.
+    pub children: Vec<TemplateContext>,
+    /// The slowest tasks across the hierarchy, if requested by the report
+    /// options, only populated for the root tracker.
+    pub slowest_leaves: Vec<TemplateLeaf>,
+}
+
+impl From<&ReportModel> for TemplateContext {
+    fn from(model: &ReportModel) -> Self {
+        Self {
+            name: model.name.clone(),
+            start: model.start,
+            total_time: model.total_time.into(),
+            self_time: model.self_time.into(),
+            tracked_time: model.tracked_time.into(),
+            parallelism: model.parallelism,
+            elapsed: model.elapsed.into(),
+            outcome_counts: model.outcome_counts,
+            successful_time: model.successful_time.into(),
+            failed_time: model.failed_time.into(),
+            untracked: model.untracked.as_ref().map(TemplateTask::from),
+            slowest_task: model.slowest_task.as_ref().map(TemplateTask::from),
+            tasks: model.rows.iter().map(TemplateTask::from).collect(),
+            children: model.children.iter().map(TemplateContext::from).collect(),
+            slowest_leaves: model.slowest_leaves.iter().map(TemplateLeaf::from).collect(),
+        }
+    }
+}
+
+/// Trait for user-supplied template engines rendering a report.
+///
+/// The trait is implemented for any closure taking a [`TemplateContext`] and
+/// returning a `Result`, so that engines can be plugged in without wrappers.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::{prelude::*, report::TemplateContext};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// tracker.add_completed_task(Task::new("Task"));
+/// let report: Report = tracker.into();
+///
+/// let engine = |context: &TemplateContext| -> Result<String, std::fmt::Error> {
+///     Ok(format!("{} ran {} tasks", context.name, context.tasks.len()))
+/// };
+/// assert_eq!(report.render_template(&engine).unwrap(), "Project ran 1 tasks");
+/// ```
+pub trait TemplateEngine {
+    /// The error returned when the template cannot be rendered.
+    type Error;
+
+    /// Renders the template with the provided context.
+    ///
+    /// # Errors
+    ///
+    /// If the template cannot be rendered, an error will be returned.
+    fn render(&self, context: &TemplateContext) -> Result<String, Self::Error>;
+}
+
+impl<F, E> TemplateEngine for F
+where
+    F: Fn(&TemplateContext) -> Result<String, E>,
+{
+    type Error = E;
+
+    fn render(&self, context: &TemplateContext) -> Result<String, Self::Error> {
+        self(context)
+    }
+}
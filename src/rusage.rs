@@ -0,0 +1,34 @@
+//! Submodule reading the resource usage of the process and of the calling
+//! thread with `getrusage`, and of the child processes with `wait4`.
+
+/// Returns the resource usage of the provided target, either the process or
+/// the calling thread, or `None` if it cannot be read.
+pub(crate) fn rusage(who: libc::c_int) -> Option<libc::rusage> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+    // SAFETY: `getrusage` only writes to the provided pointer, which points
+    // to memory large enough to hold a `rusage`.
+    let status = unsafe { libc::getrusage(who, usage.as_mut_ptr()) };
+    // SAFETY: a zero status guarantees that `getrusage` initialized `usage`.
+    (status == 0).then(|| unsafe { usage.assume_init() })
+}
+
+/// Waits for the child process with the provided identifier to terminate,
+/// returning its raw wait status and its resource usage.
+pub(crate) fn wait4(pid: libc::pid_t) -> std::io::Result<(libc::c_int, libc::rusage)> {
+    let mut status = 0;
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+    loop {
+        // SAFETY: `wait4` only writes to the provided pointers, which point to
+        // memory large enough to hold a status and a `rusage`.
+        let result = unsafe { libc::wait4(pid, &raw mut status, 0, usage.as_mut_ptr()) };
+        if result == pid {
+            // SAFETY: reaping the child guarantees that `wait4` initialized
+            // `usage`.
+            return Ok((status, unsafe { usage.assume_init() }));
+        }
+        let error = std::io::Error::last_os_error();
+        if error.kind() != std::io::ErrorKind::Interrupted {
+            return Err(error);
+        }
+    }
+}
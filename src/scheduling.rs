@@ -0,0 +1,149 @@
+//! Submodule defining the context switches and page faults of the process
+//! while a task runs, which explain slowdowns that wall time alone cannot,
+//! such as lock contention or swapping.
+
+use std::ops::{Add, Sub};
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+/// The context switches and page faults of the process.
+///
+/// Read with [`SchedulingEvents::now`], it holds the events since the
+/// process started; subtracting two readings gives the events in between.
+///
+/// # Examples
+///
+/// ```
+/// use time_requirements::scheduling::SchedulingEvents;
+///
+/// let events = SchedulingEvents {
+///     voluntary_context_switches: 120,
+///     involuntary_context_switches: 30,
+///     minor_page_faults: 2000,
+///     major_page_faults: 5,
+/// };
+/// assert_eq!(events.context_switches(), 150);
+/// assert_eq!(events.page_faults(), 2005);
+/// ```
+pub struct SchedulingEvents {
+    /// The number of times the process gave up the CPU before its time slice
+    /// ended, typically to wait for I/O or a lock.
+    pub voluntary_context_switches: u64,
+    /// The number of times the process was preempted, typically because
+    /// other processes competed for the CPU.
+    pub involuntary_context_switches: u64,
+    /// The number of page faults served without any I/O.
+    pub minor_page_faults: u64,
+    /// The number of page faults requiring I/O, such as reading swapped out
+    /// memory back.
+    pub major_page_faults: u64,
+}
+
+impl SchedulingEvents {
+    #[must_use]
+    /// Returns the context switches and page faults of the process so far,
+    /// or `None` on platforms where they cannot be measured.
+    ///
+    /// On Linux, the events are read with `getrusage`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::scheduling::SchedulingEvents;
+    ///
+    /// if let Some(before) = SchedulingEvents::now() {
+    ///     // Touching freshly mapped memory faults its pages in.
+    ///     let buffer = std::hint::black_box(vec![1_u8; 16 * 1024 * 1024]);
+    ///     let events = SchedulingEvents::now().unwrap() - before;
+    ///     assert!(events.minor_page_faults > 0);
+    ///     drop(buffer);
+    /// }
+    /// ```
+    pub fn now() -> Option<Self> {
+        #[cfg(target_os = "linux")]
+        {
+            let usage = crate::rusage::rusage(libc::RUSAGE_SELF)?;
+            Some(Self {
+                voluntary_context_switches: u64::try_from(usage.ru_nvcsw).ok()?,
+                involuntary_context_switches: u64::try_from(usage.ru_nivcsw).ok()?,
+                minor_page_faults: u64::try_from(usage.ru_minflt).ok()?,
+                major_page_faults: u64::try_from(usage.ru_majflt).ok()?,
+            })
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            None
+        }
+    }
+
+    #[must_use]
+    /// Returns the number of voluntary and involuntary context switches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::scheduling::SchedulingEvents;
+    ///
+    /// let events = SchedulingEvents {
+    ///     voluntary_context_switches: 2,
+    ///     involuntary_context_switches: 1,
+    ///     ..SchedulingEvents::default()
+    /// };
+    /// assert_eq!(events.context_switches(), 3);
+    /// ```
+    pub fn context_switches(&self) -> u64 {
+        self.voluntary_context_switches + self.involuntary_context_switches
+    }
+
+    #[must_use]
+    /// Returns the number of minor and major page faults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::scheduling::SchedulingEvents;
+    ///
+    /// let events = SchedulingEvents {
+    ///     minor_page_faults: 10,
+    ///     major_page_faults: 1,
+    ///     ..SchedulingEvents::default()
+    /// };
+    /// assert_eq!(events.page_faults(), 11);
+    /// ```
+    pub fn page_faults(&self) -> u64 {
+        self.minor_page_faults + self.major_page_faults
+    }
+}
+
+impl Add for SchedulingEvents {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            voluntary_context_switches: self.voluntary_context_switches
+                + other.voluntary_context_switches,
+            involuntary_context_switches: self.involuntary_context_switches
+                + other.involuntary_context_switches,
+            minor_page_faults: self.minor_page_faults + other.minor_page_faults,
+            major_page_faults: self.major_page_faults + other.major_page_faults,
+        }
+    }
+}
+
+impl Sub for SchedulingEvents {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            voluntary_context_switches: self
+                .voluntary_context_switches
+                .saturating_sub(other.voluntary_context_switches),
+            involuntary_context_switches: self
+                .involuntary_context_switches
+                .saturating_sub(other.involuntary_context_switches),
+            minor_page_faults: self.minor_page_faults.saturating_sub(other.minor_page_faults),
+            major_page_faults: self.major_page_faults.saturating_sub(other.major_page_faults),
+        }
+    }
+}
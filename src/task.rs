@@ -1,5 +1,18 @@
 //! Submodule defining a task to be tracked.
 
+use std::sync::Arc;
+
+use crate::{
+    allocations::AllocationCounts,
+    clock::{Clock, SharedClock},
+    cpu::CpuTime,
+    io::IoCounters,
+    memory::{MemorySample, MemoryUsage},
+    metadata::Metadata,
+    outcome::Outcome,
+    scheduling::SchedulingEvents,
+};
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash)]
 /// A task to be tracked.
 pub struct Task {
@@ -7,6 +20,38 @@ pub struct Task {
     name: String,
     /// The start time of the task.
     start: chrono::NaiveDateTime,
+    /// The metadata attached to the task.
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    metadata: Metadata,
+    /// The number of items processed by the task, if counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    items: Option<u64>,
+    /// The number of bytes processed by the task, if counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    processed_bytes: Option<u64>,
+    /// The CPU time consumed when the task started, if it measures its CPU
+    /// time.
+    #[serde(skip)]
+    cpu_start: Option<CpuTime>,
+    /// The memory used by the process when the task started, if it measures
+    /// its memory usage.
+    #[serde(skip)]
+    memory_start: Option<MemorySample>,
+    /// The allocations counted on the calling thread when the task started,
+    /// if it counts its allocations.
+    #[serde(skip)]
+    allocations_start: Option<AllocationCounts>,
+    /// The I/O counters of the process when the task started, if it measures
+    /// its I/O.
+    #[serde(skip)]
+    io_start: Option<IoCounters>,
+    /// The context switches and page faults of the process when the task
+    /// started, if it counts them.
+    #[serde(skip)]
+    scheduling_start: Option<SchedulingEvents>,
+    /// The clock the task was started with, read when it completes.
+    #[serde(skip)]
+    clock: SharedClock,
 }
 
 impl Task {
@@ -28,7 +73,293 @@ impl Task {
     /// assert_eq!(task3.name(), "My Task");
     /// ```
     pub fn new<S: ToString + ?Sized>(name: &S) -> Self {
-        Self { name: name.to_string(), start: chrono::Local::now().naive_local() }
+        Self::with_shared_clock(name, SharedClock::default().0)
+    }
+
+    /// Create a new task with the given name, started at the current time of
+    /// the provided clock, which is also read when the task completes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{
+    ///     clock::{Clock, MockClock},
+    ///     task::Task,
+    /// };
+    ///
+    /// let clock = MockClock::default();
+    /// let task = Task::with_clock("My Task", &clock);
+    /// clock.advance(chrono::TimeDelta::seconds(1));
+    ///
+    /// let completed = task.complete();
+    /// assert_eq!(completed.time(), chrono::TimeDelta::seconds(1));
+    /// ```
+    pub fn with_clock<S: ToString + ?Sized, C: Clock + Clone + 'static>(
+        name: &S,
+        clock: &C,
+    ) -> Self {
+        Self::with_shared_clock(name, Arc::new(clock.clone()))
+    }
+
+    /// Creates a new task with the given name, started at the current time of
+    /// the provided shared clock.
+    pub(crate) fn with_shared_clock<S: ToString + ?Sized>(name: &S, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            name: name.to_string(),
+            start: clock.now(),
+            metadata: Metadata::default(),
+            items: None,
+            processed_bytes: None,
+            cpu_start: None,
+            memory_start: None,
+            allocations_start: None,
+            io_start: None,
+            scheduling_start: None,
+            clock: SharedClock(clock),
+        }
+    }
+
+    #[must_use]
+    /// Measures the CPU time consumed by the process and by the calling
+    /// thread while the task runs, on the platforms supporting it.
+    ///
+    /// The thread CPU time is only meaningful if the task is completed on
+    /// the thread which started it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let task = Task::new("Sum").with_cpu_time();
+    /// let _sum: u64 = (0..1_000_000).sum();
+    /// let completed = task.complete();
+    ///
+    /// if cfg!(target_os = "linux") {
+    ///     assert!(completed.cpu_time().is_some());
+    ///     assert!(completed.cpu_utilization().is_some());
+    /// }
+    /// assert!(Task::new("Idle").complete().cpu_time().is_none());
+    /// ```
+    pub fn with_cpu_time(mut self) -> Self {
+        self.cpu_start = CpuTime::now();
+        self
+    }
+
+    #[must_use]
+    /// Measures the resident set size of the process when the task starts
+    /// and completes, and its peak in between, on the platforms supporting
+    /// it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let task = Task::new("Load").with_memory_usage();
+    /// let buffer = vec![1_u8; 16 * 1024 * 1024];
+    /// let completed = task.complete();
+    /// drop(buffer);
+    ///
+    /// if let Some(memory) = completed.memory_usage() {
+    ///     assert!(memory.peak_rss >= memory.start_rss.max(memory.end_rss).unwrap());
+    /// }
+    /// assert!(Task::new("Idle").complete().memory_usage().is_none());
+    /// ```
+    pub fn with_memory_usage(mut self) -> Self {
+        self.memory_start = MemorySample::now();
+        self
+    }
+
+    #[must_use]
+    /// Counts the allocations performed on the calling thread while the task
+    /// runs, if a [`CountingAllocator`](crate::allocations::CountingAllocator)
+    /// is installed as the global allocator.
+    ///
+    /// The counts are only meaningful if the task is completed on the thread
+    /// which started it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// // Without a counting allocator, nothing is recorded.
+    /// let completed = Task::new("Collect").with_allocations().complete();
+    /// assert!(completed.allocations().is_none());
+    /// ```
+    pub fn with_allocations(mut self) -> Self {
+        self.allocations_start = AllocationCounts::current();
+        self
+    }
+
+    #[must_use]
+    /// Measures the bytes read and written and the read and write syscalls
+    /// of the process while the task runs, on the platforms supporting it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let task = Task::new("Load").with_io_counters();
+    /// let _manifest = std::fs::read_to_string("Cargo.toml").unwrap();
+    /// let completed = task.complete();
+    ///
+    /// if let Some(io) = completed.io_counters() {
+    ///     assert!(io.read_bytes > 0);
+    /// }
+    /// assert!(Task::new("Idle").complete().io_counters().is_none());
+    /// ```
+    pub fn with_io_counters(mut self) -> Self {
+        self.io_start = IoCounters::now();
+        self
+    }
+
+    #[must_use]
+    /// Counts the voluntary and involuntary context switches and the minor
+    /// and major page faults of the process while the task runs, on the
+    /// platforms supporting it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let task = Task::new("Fill").with_scheduling_events();
+    /// let buffer = std::hint::black_box(vec![1_u8; 16 * 1024 * 1024]);
+    /// let completed = task.complete();
+    /// drop(buffer);
+    ///
+    /// if let Some(events) = completed.scheduling_events() {
+    ///     assert!(events.minor_page_faults > 0);
+    /// }
+    /// assert!(Task::new("Idle").complete().scheduling_events().is_none());
+    /// ```
+    pub fn with_scheduling_events(mut self) -> Self {
+        self.scheduling_start = SchedulingEvents::now();
+        self
+    }
+
+    #[must_use]
+    /// Attaches the provided tag to the task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let task = Task::new("Parse").with_tag("io");
+    /// assert!(task.metadata().has_tag("io"));
+    /// ```
+    pub fn with_tag<S: ToString + ?Sized>(mut self, tag: &S) -> Self {
+        self.metadata.add_tag(tag);
+        self
+    }
+
+    #[must_use]
+    /// Sets the attribute with the provided key on the task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let task = Task::new("Parse")
+    ///     .with_attribute("input", "spectra.mgf")
+    ///     .with_attribute("size", &42);
+    /// assert_eq!(task.metadata().attribute("input"), Some("spectra.mgf"));
+    /// assert_eq!(task.metadata().attribute("size"), Some("42"));
+    /// ```
+    pub fn with_attribute<K: ToString + ?Sized, V: ToString + ?Sized>(
+        mut self,
+        key: &K,
+        value: &V,
+    ) -> Self {
+        self.metadata.set_attribute(key, value);
+        self
+    }
+
+    #[must_use]
+    /// Adds the provided free-form note to the task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let task = Task::new("Parse").with_note("Cold cache");
+    /// assert_eq!(task.metadata().notes(), ["Cold cache"]);
+    /// ```
+    pub fn with_note<S: ToString + ?Sized>(mut self, note: &S) -> Self {
+        self.metadata.add_note(note);
+        self
+    }
+
+    #[must_use]
+    /// Returns the metadata attached to the task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let task = Task::new("My Task");
+    /// assert!(task.metadata().is_empty());
+    /// ```
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Returns a mutable reference to the metadata attached to the task, to
+    /// annotate it while it runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let mut task = Task::new("My Task");
+    /// task.metadata_mut().set_attribute("rows", &1000);
+    /// assert_eq!(task.metadata().attribute("rows"), Some("1000"));
+    /// ```
+    pub fn metadata_mut(&mut self) -> &mut Metadata {
+        &mut self.metadata
+    }
+
+    /// Adds the provided number of items to those processed by the task, so
+    /// that the report can normalize its time by the work done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let mut task = Task::new("Annotate");
+    /// for batch in [[1, 2, 3], [4, 5, 6]] {
+    ///     task.add_items(batch.len() as u64);
+    /// }
+    /// assert_eq!(task.complete().items(), Some(6));
+    /// ```
+    pub fn add_items(&mut self, items: u64) {
+        self.items = Some(self.items.unwrap_or_default() + items);
+    }
+
+    /// Adds the provided number of bytes to those processed by the task, so
+    /// that the report can show its throughput.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let mut task = Task::new("Checksum");
+    /// task.add_bytes(4096);
+    /// task.add_bytes(1024);
+    /// assert_eq!(task.complete().processed_bytes(), Some(5120));
+    /// ```
+    pub fn add_bytes(&mut self, bytes: u64) {
+        self.processed_bytes = Some(self.processed_bytes.unwrap_or_default() + bytes);
     }
 
     /// Returns the name of the task.
@@ -47,7 +378,9 @@ impl Task {
     }
 
     #[must_use]
-    /// Marks the task as completed.
+    /// Marks the task as completed at the current time of the clock it was
+    /// started with, the system clock unless started by a tracker or with
+    /// [`with_clock`](Self::with_clock).
     ///
     /// # Examples
     ///
@@ -59,12 +392,182 @@ impl Task {
     /// assert_eq!(completed.name(), "My Task");
     /// ```
     pub fn complete(self) -> CompletedTask {
+        let end = self.clock.0.now();
+        self.complete_at(end)
+    }
+
+    #[must_use]
+    /// Marks the task as completed at the provided time, as read from a
+    /// [`Clock`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{
+    ///     clock::{Clock, MockClock},
+    ///     task::Task,
+    /// };
+    ///
+    /// let clock = MockClock::default();
+    /// let task = Task::with_clock("My Task", &clock);
+    /// clock.advance(chrono::TimeDelta::milliseconds(10));
+    /// assert_eq!(task.complete_at(clock.now()).time(), chrono::TimeDelta::milliseconds(10));
+    /// ```
+    pub fn complete_at(self, end: chrono::NaiveDateTime) -> CompletedTask {
         CompletedTask {
             name: self.name,
             start: self.start,
-            end: chrono::Local::now().naive_local(),
+            end,
+            busy_time: None,
+            io_time: None,
+            metadata: self.metadata,
+            items: self.items,
+            processed_bytes: self.processed_bytes,
+            outcome: Outcome::Success,
+            cpu: self.cpu_start.and_then(|start| CpuTime::now().map(|end| end - start)),
+            memory: self
+                .memory_start
+                .and_then(|start| MemorySample::now().map(|end| MemoryUsage::between(start, end))),
+            allocations: self
+                .allocations_start
+                .and_then(|start| AllocationCounts::current().map(|end| end - start)),
+            io: self.io_start.and_then(|start| IoCounters::now().map(|end| end - start)),
+            scheduling: self
+                .scheduling_start
+                .and_then(|start| SchedulingEvents::now().map(|end| end - start)),
         }
     }
+
+    #[must_use]
+    /// Marks the task as completed with the provided outcome, which may also
+    /// be derived from a `Result`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{outcome::Outcome, task::Task};
+    ///
+    /// let completed = Task::new("Download").complete_with(Outcome::cancelled("Interrupted"));
+    /// assert_eq!(completed.outcome().status(), "cancelled");
+    ///
+    /// let result: Result<u32, std::num::ParseIntError> = "forty-two".parse();
+    /// let completed = Task::new("Parse").complete_with(&result);
+    /// assert!(completed.outcome().is_failure());
+    /// ```
+    ///
+    /// A task started by a tracker completes at the time of the clock of the
+    /// tracker, and can then be added to it:
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use time_requirements::{clock::MockClock, outcome::Outcome, prelude::*};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let task = tracker.start_task("Fetch");
+    /// clock.advance(TimeDelta::seconds(2));
+    /// tracker.add_completed_task(task.complete_with(Outcome::skipped("Cached")));
+    ///
+    /// let fetch = tracker.get("Fetch").unwrap();
+    /// assert_eq!(fetch.outcome().status(), "skipped");
+    /// assert_eq!(fetch.time(), TimeDelta::seconds(2));
+    /// ```
+    pub fn complete_with<O: Into<Outcome>>(self, outcome: O) -> CompletedTask {
+        let mut completed = self.complete();
+        completed.outcome = outcome.into();
+        completed
+    }
+
+    #[must_use]
+    /// Marks the task as completed after processing the provided number of
+    /// items, in addition to those already added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let completed = Task::new("Annotate").complete_with_items(10_000);
+    /// assert_eq!(completed.items(), Some(10_000));
+    /// assert!(completed.time_per_item().is_some());
+    /// ```
+    pub fn complete_with_items(mut self, items: u64) -> CompletedTask {
+        self.add_items(items);
+        self.complete()
+    }
+
+    #[must_use]
+    /// Marks the task as completed after processing the provided number of
+    /// bytes, in addition to those already added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let completed = Task::new("Download").complete_with_bytes(64 * 1024 * 1024);
+    /// assert_eq!(completed.processed_bytes(), Some(64 * 1024 * 1024));
+    /// ```
+    pub fn complete_with_bytes(mut self, bytes: u64) -> CompletedTask {
+        self.add_bytes(bytes);
+        self.complete()
+    }
+}
+
+/// Trait for the values a tracker can start a task from: either a task
+/// already started, or the name of a task to start at the current time of
+/// the clock of the tracker.
+pub trait IntoTask {
+    /// Converts the value into a task, started at the current time of the
+    /// provided clock unless it already is a task.
+    fn into_task(self, clock: &Arc<dyn Clock>) -> Task;
+}
+
+impl IntoTask for Task {
+    fn into_task(self, _clock: &Arc<dyn Clock>) -> Task {
+        self
+    }
+}
+
+impl IntoTask for &str {
+    fn into_task(self, clock: &Arc<dyn Clock>) -> Task {
+        Task::with_shared_clock(self, Arc::clone(clock))
+    }
+}
+
+impl IntoTask for String {
+    fn into_task(self, clock: &Arc<dyn Clock>) -> Task {
+        Task::with_shared_clock(&self, Arc::clone(clock))
+    }
+}
+
+impl IntoTask for &String {
+    fn into_task(self, clock: &Arc<dyn Clock>) -> Task {
+        Task::with_shared_clock(self, Arc::clone(clock))
+    }
+}
+
+/// Trait for the values a tracker can record as a completed task: either a
+/// task already completed, or a task or the name of a task as accepted by
+/// [`IntoTask`], completed with [`Task::complete`] on the clock it was
+/// started with, which is the clock of the tracker for the names.
+pub trait IntoCompletedTask {
+    /// Converts the value into a completed task, starting it on the provided
+    /// clock if it is not a task yet and completing it on the clock it was
+    /// started with, unless it already is a completed task.
+    fn into_completed_task(self, clock: &Arc<dyn Clock>) -> CompletedTask;
+}
+
+impl<T: IntoTask> IntoCompletedTask for T {
+    fn into_completed_task(self, clock: &Arc<dyn Clock>) -> CompletedTask {
+        self.into_task(clock).complete()
+    }
+}
+
+impl IntoCompletedTask for CompletedTask {
+    fn into_completed_task(self, _clock: &Arc<dyn Clock>) -> CompletedTask {
+        self
+    }
 }
 
 impl From<&str> for Task {
@@ -88,6 +591,46 @@ pub struct CompletedTask {
     pub(crate) start: chrono::NaiveDateTime,
     /// The end time of the task.
     pub(crate) end: chrono::NaiveDateTime,
+    /// The time during which the task was actually running, if shorter than
+    /// the time between its start and end, as for the tasks summarizing
+    /// sub-trackers whose tasks are interleaved with other tasks.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "crate::nanoseconds::option")]
+    pub(crate) busy_time: Option<chrono::TimeDelta>,
+    /// The time spent inside the reads and writes of the task, if it was
+    /// recorded by a timed reader or writer.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "crate::nanoseconds::option")]
+    pub(crate) io_time: Option<chrono::TimeDelta>,
+    /// The metadata attached to the task.
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub(crate) metadata: Metadata,
+    /// The number of items processed by the task, if counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) items: Option<u64>,
+    /// The number of bytes processed by the task, if counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) processed_bytes: Option<u64>,
+    /// The outcome of the task.
+    #[serde(default, skip_serializing_if = "Outcome::is_success")]
+    pub(crate) outcome: Outcome,
+    /// The CPU time consumed while the task ran, if it was measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) cpu: Option<CpuTime>,
+    /// The memory used by the process while the task ran, if it was
+    /// measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) memory: Option<MemoryUsage>,
+    /// The allocations performed on the thread of the task while it ran, if
+    /// they were counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) allocations: Option<AllocationCounts>,
+    /// The I/O performed by the process while the task ran, if it was
+    /// measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) io: Option<IoCounters>,
+    /// The context switches and page faults of the process while the task
+    /// ran, if they were counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) scheduling: Option<SchedulingEvents>,
 }
 
 impl CompletedTask {
@@ -108,59 +651,447 @@ impl CompletedTask {
     }
 
     #[must_use]
-    /// Returns the time required to complete the task.
+    /// Returns the task renamed with the provided name, which may be a
+    /// slash-separated path recording it under the named sub-trackers once
+    /// added to a tracker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut scratch = TimeTracker::new("Scratch");
+    /// scratch.add_completed_task("Compile");
+    /// let completed = scratch.get("Compile").unwrap().clone();
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(completed.with_name("build/compile"));
+    /// assert!(tracker.get("build/compile").is_some());
+    /// ```
+    pub fn with_name<S: ToString + ?Sized>(mut self, name: &S) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    #[must_use]
+    /// Returns the start time of the task.
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::{thread, time::Duration};
+    /// use time_requirements::task::Task;
     ///
+    /// let completed = Task::new("My Task").complete();
+    /// assert!(completed.start() <= completed.end());
+    /// ```
+    pub fn start(&self) -> chrono::NaiveDateTime {
+        self.start
+    }
+
+    #[must_use]
+    /// Returns the end time of the task.
+    ///
+    /// # Examples
+    ///
+    /// ```
     /// use time_requirements::task::Task;
     ///
-    /// let task = Task::new("My Task");
-    /// thread::sleep(Duration::from_millis(10));
-    /// let completed = task.complete();
-    /// let time = completed.time();
-    /// assert!(time.num_milliseconds() >= 10);
-    /// assert!(time.num_milliseconds() > 0);
+    /// let completed = Task::new("My Task").complete();
+    /// assert_eq!(completed.end() - completed.start(), completed.time());
+    /// ```
+    pub fn end(&self) -> chrono::NaiveDateTime {
+        self.end
+    }
+
+    #[must_use]
+    /// Returns the time required to complete the task: the time between its
+    /// start and end, or for a task summarizing a sub-tracker, the
+    /// wall-clock time covered by the tasks of the sub-tracker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{
+    ///     clock::{Clock, MockClock},
+    ///     task::Task,
+    /// };
+    ///
+    /// let clock = MockClock::default();
+    /// let task = Task::with_clock("My Task", &clock);
+    /// clock.advance(chrono::TimeDelta::milliseconds(10));
+    /// let completed = task.complete_at(clock.now());
+    /// assert_eq!(completed.time().num_milliseconds(), 10);
     /// ```
     pub fn time(&self) -> chrono::TimeDelta {
-        self.end - self.start
+        self.busy_time.unwrap_or(self.end - self.start)
     }
 
-    /// Extends the completed task by another completed task.
+    #[must_use]
+    /// Returns the metadata attached to the task.
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::{thread, time::Duration};
+    /// use time_requirements::task::Task;
+    ///
+    /// let completed = Task::new("My Task").with_tag("io").complete();
+    /// assert!(completed.metadata().has_tag("io"));
+    /// ```
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Returns a mutable reference to the metadata attached to the task.
+    ///
+    /// # Examples
     ///
+    /// ```
     /// use time_requirements::task::Task;
     ///
-    /// let task1 = Task::new("Task 1");
-    /// thread::sleep(Duration::from_millis(10));
-    /// let mut completed1 = task1.complete();
-    /// let original_time = completed1.time();
+    /// let mut completed = Task::new("My Task").complete();
+    /// completed.metadata_mut().add_note("Retried once");
+    /// assert_eq!(completed.metadata().notes(), ["Retried once"]);
+    /// ```
+    pub fn metadata_mut(&mut self) -> &mut Metadata {
+        &mut self.metadata
+    }
+
+    #[must_use]
+    /// Returns the outcome of the task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{outcome::Outcome, task::Task};
+    ///
+    /// assert!(Task::new("My Task").complete().outcome().is_success());
+    ///
+    /// let completed = Task::new("My Task").complete_with(Outcome::failure("Disk full"));
+    /// assert_eq!(completed.outcome().message(), Some("Disk full"));
+    /// ```
+    pub fn outcome(&self) -> &Outcome {
+        &self.outcome
+    }
+
+    #[must_use]
+    /// Returns the number of items processed by the task, if counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// assert_eq!(Task::new("Annotate").complete_with_items(42).items(), Some(42));
+    /// assert_eq!(Task::new("Annotate").complete().items(), None);
+    /// ```
+    pub fn items(&self) -> Option<u64> {
+        self.items
+    }
+
+    #[must_use]
+    /// Returns the number of bytes processed by the task, if counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// assert_eq!(Task::new("Download").complete_with_bytes(512).processed_bytes(), Some(512));
+    /// ```
+    pub fn processed_bytes(&self) -> Option<u64> {
+        self.processed_bytes
+    }
+
+    #[must_use]
+    /// Returns the time spent inside the reads and writes of the task, if it
+    /// was recorded by a [`TimedReader`](crate::timed_io::TimedReader) or a
+    /// [`TimedWriter`](crate::timed_io::TimedWriter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Read;
+    ///
+    /// use time_requirements::{prelude::*, timed_io::TimedReader};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut reader = TimedReader::new(&b"abc"[..], "read", &mut tracker);
+    /// reader.read_to_end(&mut Vec::new()).unwrap();
+    /// reader.finish();
+    ///
+    /// let read = tracker.get("read").unwrap();
+    /// assert!(read.io_time().unwrap() <= read.time());
+    /// assert!(Task::new("Idle").complete().io_time().is_none());
+    /// ```
+    pub fn io_time(&self) -> Option<chrono::TimeDelta> {
+        self.io_time
+    }
+
+    #[must_use]
+    /// Returns the number of items processed per second, if counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{
+    ///     clock::{Clock, MockClock},
+    ///     task::Task,
+    /// };
+    ///
+    /// let clock = MockClock::default();
+    /// let mut task = Task::with_clock("Annotate", &clock);
+    /// task.add_items(10_000);
+    /// clock.advance(chrono::TimeDelta::seconds(2));
+    ///
+    /// assert_eq!(task.complete_at(clock.now()).items_per_second(), Some(5000.0));
+    /// ```
+    pub fn items_per_second(&self) -> Option<f64> {
+        self.items.map(|items| per_second(items, self.time()))
+    }
+
+    #[must_use]
+    /// Returns the number of bytes processed per second, if counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{
+    ///     clock::{Clock, MockClock},
+    ///     task::Task,
+    /// };
     ///
-    /// let task2 = Task::new("Task 2");
-    /// thread::sleep(Duration::from_millis(10));
-    /// let completed2 = task2.complete();
+    /// let clock = MockClock::default();
+    /// let mut task = Task::with_clock("Download", &clock);
+    /// task.add_bytes(1024);
+    /// clock.advance(chrono::TimeDelta::milliseconds(500));
+    ///
+    /// assert_eq!(task.complete_at(clock.now()).bytes_per_second(), Some(2048.0));
+    /// ```
+    pub fn bytes_per_second(&self) -> Option<f64> {
+        self.processed_bytes.map(|bytes| per_second(bytes, self.time()))
+    }
+
+    #[must_use]
+    /// Returns the average time spent on each item, if items were counted
+    /// and there is at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{
+    ///     clock::{Clock, MockClock},
+    ///     task::Task,
+    /// };
+    ///
+    /// let clock = MockClock::default();
+    /// let mut task = Task::with_clock("Annotate", &clock);
+    /// task.add_items(4);
+    /// clock.advance(chrono::TimeDelta::seconds(1));
+    ///
+    /// let completed = task.complete_at(clock.now());
+    /// assert_eq!(completed.time_per_item(), Some(chrono::TimeDelta::milliseconds(250)));
+    /// ```
+    pub fn time_per_item(&self) -> Option<chrono::TimeDelta> {
+        time_per_item(self.items?, self.time())
+    }
+
+    #[must_use]
+    /// Returns the CPU time consumed while the task ran, if it was measured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let task = Task::new("Sum").with_cpu_time();
+    /// let _sum: u64 = (0..1_000_000).map(std::hint::black_box).sum();
+    /// let completed = task.complete();
+    /// if cfg!(target_os = "linux") {
+    ///     assert!(completed.cpu_time().unwrap().thread() > chrono::TimeDelta::zero());
+    /// } else {
+    ///     assert!(completed.cpu_time().is_none());
+    /// }
+    /// ```
+    pub fn cpu_time(&self) -> Option<CpuTime> {
+        self.cpu
+    }
+
+    #[must_use]
+    /// Returns the CPU time of the process while the task ran as a
+    /// percentage of the wall-clock time of the task, if it was measured.
+    ///
+    /// A task waiting on I/O has a low utilization, while a task keeping
+    /// several threads busy exceeds one hundred percent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let completed = Task::new("Sum").with_cpu_time().complete();
+    /// if let Some(utilization) = completed.cpu_utilization() {
+    ///     assert!(utilization >= 0.0);
+    /// }
+    /// ```
+    pub fn cpu_utilization(&self) -> Option<f64> {
+        self.cpu.map(|cpu| cpu.utilization(self.time()))
+    }
+
+    #[must_use]
+    /// Returns the memory used by the process while the task ran, if it was
+    /// measured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let completed = Task::new("Load").with_memory_usage().complete();
+    /// assert_eq!(completed.memory_usage().is_some(), cfg!(target_os = "linux"));
+    /// ```
+    pub fn memory_usage(&self) -> Option<MemoryUsage> {
+        self.memory
+    }
+
+    #[must_use]
+    /// Returns the allocations performed on the thread of the task while it
+    /// ran, if they were counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// assert!(Task::new("Collect").complete().allocations().is_none());
+    /// ```
+    pub fn allocations(&self) -> Option<AllocationCounts> {
+        self.allocations
+    }
+
+    #[must_use]
+    /// Returns the I/O performed by the process while the task ran, if it
+    /// was measured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let completed = Task::new("Load").with_io_counters().complete();
+    /// assert_eq!(completed.io_counters().is_some(), cfg!(target_os = "linux"));
+    /// ```
+    pub fn io_counters(&self) -> Option<IoCounters> {
+        self.io
+    }
+
+    #[must_use]
+    /// Returns the bytes read and written per second by the process while
+    /// the task ran, if its I/O was measured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let completed = Task::new("Load").with_io_counters().complete();
+    /// if let Some(throughput) = completed.io_throughput() {
+    ///     assert!(throughput >= 0.0);
+    /// }
+    /// ```
+    pub fn io_throughput(&self) -> Option<f64> {
+        self.io.map(|io| io.throughput(self.time()))
+    }
+
+    #[must_use]
+    /// Returns the context switches and page faults of the process while the
+    /// task ran, if they were counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::task::Task;
+    ///
+    /// let completed = Task::new("Fill").with_scheduling_events().complete();
+    /// assert_eq!(completed.scheduling_events().is_some(), cfg!(target_os = "linux"));
+    /// ```
+    pub fn scheduling_events(&self) -> Option<SchedulingEvents> {
+        self.scheduling
+    }
+
+    /// Extends the completed task by another completed task, merging the
+    /// metadata of the other task into its own, adding up their processed
+    /// items and bytes, CPU times, allocations, I/O and scheduling events,
+    /// chaining their memory usages and taking its outcome unless it
+    /// succeeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use time_requirements::{
+    ///     clock::{Clock, MockClock},
+    ///     task::Task,
+    /// };
+    ///
+    /// let clock = MockClock::default();
+    /// let task1 = Task::with_clock("Task 1", &clock);
+    /// clock.advance(TimeDelta::milliseconds(10));
+    /// let mut completed1 = task1.complete_at(clock.now());
+    ///
+    /// let task2 = Task::with_clock("Task 2", &clock);
+    /// clock.advance(TimeDelta::milliseconds(10));
+    /// let completed2 = task2.complete_at(clock.now());
     /// completed1.extend(&completed2);
-    /// assert!(completed1.time() > original_time);
+    /// assert_eq!(completed1.time(), TimeDelta::milliseconds(20));
     ///
     /// // Extend again
-    /// let task3 = Task::new("Task 3");
-    /// thread::sleep(Duration::from_millis(5));
-    /// let completed3 = task3.complete();
-    /// let before_second_extend = completed1.time();
+    /// let task3 = Task::with_clock("Task 3", &clock);
+    /// clock.advance(TimeDelta::milliseconds(5));
+    /// let completed3 = task3.complete_at(clock.now());
     /// completed1.extend(&completed3);
-    /// assert!(completed1.time() > before_second_extend);
+    /// assert_eq!(completed1.time(), TimeDelta::milliseconds(25));
     ///
     /// // Comparison using PartialOrd
     /// assert!(completed2 > completed3); // completed2 took longer
     /// ```
     pub fn extend(&mut self, other: &CompletedTask) {
         self.end += other.time();
+        self.metadata.merge(&other.metadata);
+        self.items = match (self.items, other.items) {
+            (Some(items), Some(other_items)) => Some(items + other_items),
+            (items, other_items) => items.or(other_items),
+        };
+        self.processed_bytes = match (self.processed_bytes, other.processed_bytes) {
+            (Some(bytes), Some(other_bytes)) => Some(bytes + other_bytes),
+            (bytes, other_bytes) => bytes.or(other_bytes),
+        };
+        self.io_time = match (self.io_time, other.io_time) {
+            (Some(time), Some(other_time)) => Some(time + other_time),
+            (time, other_time) => time.or(other_time),
+        };
+        self.cpu = match (self.cpu, other.cpu) {
+            (Some(cpu), Some(other_cpu)) => Some(cpu + other_cpu),
+            (cpu, other_cpu) => cpu.or(other_cpu),
+        };
+        self.memory = match (self.memory, other.memory) {
+            (Some(memory), Some(other_memory)) => Some(memory.then(other_memory)),
+            (memory, other_memory) => memory.or(other_memory),
+        };
+        self.allocations = match (self.allocations, other.allocations) {
+            (Some(allocations), Some(other_allocations)) => Some(allocations + other_allocations),
+            (allocations, other_allocations) => allocations.or(other_allocations),
+        };
+        self.io = match (self.io, other.io) {
+            (Some(io), Some(other_io)) => Some(io + other_io),
+            (io, other_io) => io.or(other_io),
+        };
+        self.scheduling = match (self.scheduling, other.scheduling) {
+            (Some(events), Some(other_events)) => Some(events + other_events),
+            (events, other_events) => events.or(other_events),
+        };
+        if !other.outcome.is_success() {
+            self.outcome = other.outcome.clone();
+        }
     }
 
     /// Returns the most precise percentage over the provided `TimeDelta`.
@@ -179,19 +1110,21 @@ impl CompletedTask {
     /// # Examples
     ///
     /// ```
-    /// use std::{thread, time::Duration};
-    ///
     /// use chrono::TimeDelta;
-    /// use time_requirements::task::Task;
+    /// use time_requirements::{
+    ///     clock::{Clock, MockClock},
+    ///     task::Task,
+    /// };
     ///
-    /// let task = Task::new("Task");
-    /// thread::sleep(Duration::from_millis(100));
-    /// let completed = task.complete();
+    /// let clock = MockClock::default();
+    /// let task = Task::with_clock("Task", &clock);
+    /// clock.advance(TimeDelta::milliseconds(100));
+    /// let completed = task.complete_at(clock.now());
     ///
     /// // Total time larger than task time
     /// let total_time = TimeDelta::milliseconds(200);
     /// let percentage = completed.precise_percentage_over(total_time);
-    /// assert!(percentage > 40.0 && percentage < 60.0);
+    /// assert!((percentage - 50.0).abs() < 0.01);
     ///
     /// // Total time equal to task time
     /// let total_time = completed.time();
@@ -201,7 +1134,7 @@ impl CompletedTask {
     /// // Total time smaller than task time
     /// let total_time = TimeDelta::milliseconds(50);
     /// let percentage = completed.precise_percentage_over(total_time);
-    /// assert!(percentage > 100.0);
+    /// assert!((percentage - 200.0).abs() < 0.01);
     /// ```
     #[must_use]
     #[allow(clippy::cast_precision_loss)]
@@ -215,6 +1148,23 @@ impl CompletedTask {
     }
 }
 
+/// Returns the provided count divided by the provided time in seconds, or
+/// zero if the time is not positive.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn per_second(count: u64, time: chrono::TimeDelta) -> f64 {
+    match time.num_nanoseconds() {
+        Some(nanoseconds) if nanoseconds > 0 => count as f64 / (nanoseconds as f64 / 1e9),
+        _ => 0.0,
+    }
+}
+
+/// Returns the provided time divided by the provided number of items, or
+/// `None` if there are no items.
+pub(crate) fn time_per_item(items: u64, time: chrono::TimeDelta) -> Option<chrono::TimeDelta> {
+    let items = i64::try_from(items).ok().filter(|items| *items > 0)?;
+    Some(chrono::TimeDelta::nanoseconds(time.num_nanoseconds()? / items))
+}
+
 impl From<Task> for CompletedTask {
     fn from(task: Task) -> Self {
         task.complete()
@@ -1,23 +1,152 @@
 //! Submodule defining the task tracker.
 
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
 use crate::{
+    allocations::AllocationCounts,
+    clock::{Clock, SystemClock},
+    cpu::CpuTime,
+    hierarchy::PATH_SEPARATOR,
+    io::IoCounters,
+    memory::MemoryUsage,
+    metadata::Metadata,
+    outcome::{Outcome, OutcomeCounts},
     report::Report,
-    task::{CompletedTask, Task},
+    scheduling::SchedulingEvents,
+    task::{CompletedTask, IntoCompletedTask, Task},
 };
 
+mod editing;
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-/// A tracker for tasks.
-pub struct TimeTracker {
+/// A task of a tracker, paired with the sub-tracker it summarizes, if any.
+struct Entry {
+    /// The task.
+    #[serde(flatten)]
+    task: CompletedTask,
+    /// The sub-tracker summarized by the task, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sub_tracker: Option<TimeTracker>,
+}
+
+/// A wall-clock interval, from its start to its end.
+type Interval = (chrono::NaiveDateTime, chrono::NaiveDateTime);
+
+/// Returns the union of the provided intervals, as sorted disjoint intervals.
+fn merge_intervals(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.retain(|(start, end)| start < end);
+    intervals.sort_unstable();
+    let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+impl Entry {
+    /// Returns the disjoint intervals during which the task was running: the
+    /// interval of the task itself, or those covered by the tasks of the
+    /// sub-tracker it summarizes, which may be interleaved with other tasks.
+    fn busy_intervals(&self) -> Vec<Interval> {
+        match &self.sub_tracker {
+            Some(sub_tracker) => sub_tracker.busy_intervals(),
+            None => merge_intervals(vec![(self.task.start, self.task.end)]),
+        }
+    }
+
+    /// Updates the task summarizing the sub-tracker, if any, from its current
+    /// tasks, assuming the summaries of its own sub-trackers are up to date.
+    fn update_summary(&mut self) {
+        let Some(sub_tracker) = self.sub_tracker.as_ref() else {
+            return;
+        };
+        let summary = CompletedTask::from(sub_tracker);
+        self.task.name = summary.name;
+        self.task.start = summary.start;
+        self.task.end = summary.end;
+        self.task.busy_time = summary.busy_time;
+        self.task.io_time = summary.io_time;
+        self.task.outcome = summary.outcome;
+        self.task.cpu = summary.cpu;
+        self.task.memory = summary.memory;
+        self.task.allocations = summary.allocations;
+        self.task.io = summary.io;
+        self.task.scheduling = summary.scheduling;
+    }
+}
+
+impl From<CompletedTask> for Entry {
+    fn from(task: CompletedTask) -> Self {
+        Self { task, sub_tracker: None }
+    }
+}
+
+impl From<TimeTracker> for Entry {
+    fn from(tracker: TimeTracker) -> Self {
+        Self { task: CompletedTask::from(&tracker), sub_tracker: Some(tracker) }
+    }
+}
+
+#[derive(serde::Deserialize)]
+/// The serialized form of a tracker, which also accepts the sub-trackers
+/// stored separately from the tasks summarizing them, as in older versions.
+struct SerializedTimeTracker {
     /// Name of the overall project.
     name: String,
     /// The tasks being tracked.
-    tasks: Vec<CompletedTask>,
-    /// The sub-trackers being tracked.
+    tasks: Vec<Entry>,
+    /// The sub-trackers stored separately from their tasks.
+    #[serde(default)]
     sub_trackers: Vec<TimeTracker>,
     /// Start of the project.
     start: chrono::NaiveDateTime,
+    /// End of the project, if it was explicitly finished.
+    #[serde(default)]
+    end: Option<chrono::NaiveDateTime>,
+}
+
+impl From<SerializedTimeTracker> for TimeTracker {
+    fn from(serialized: SerializedTimeTracker) -> Self {
+        let mut tasks = serialized.tasks;
+        for sub_tracker in serialized.sub_trackers {
+            if let Some(entry) = tasks.iter_mut().find(|entry| {
+                entry.sub_tracker.is_none()
+                    && entry.task.name == sub_tracker.name
+                    && entry.task.start == sub_tracker.start
+            }) {
+                entry.sub_tracker = Some(sub_tracker);
+            }
+        }
+        Self {
+            name: serialized.name,
+            tasks,
+            start: serialized.start,
+            end: serialized.end,
+            clock: Arc::new(SystemClock),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(from = "SerializedTimeTracker")]
+/// A tracker for tasks.
+pub struct TimeTracker {
+    /// Name of the overall project.
+    name: String,
+    /// The tasks being tracked, each with the sub-tracker it summarizes.
+    tasks: Vec<Entry>,
+    /// Start of the project.
+    start: chrono::NaiveDateTime,
+    /// End of the project, if it was explicitly finished.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    end: Option<chrono::NaiveDateTime>,
+    /// The clock providing the current time, shared with the sub-trackers
+    /// created by the tracker.
+    #[serde(skip)]
+    clock: Arc<dyn Clock>,
 }
 
 impl TimeTracker {
@@ -32,21 +161,168 @@ impl TimeTracker {
     /// assert_eq!(tracker.name(), "My Project");
     /// ```
     pub fn new<S: ToString + ?Sized>(name: &S) -> Self {
-        Self {
-            name: name.to_string(),
-            tasks: Vec::new(),
-            sub_trackers: Vec::new(),
-            start: chrono::Local::now().naive_local(),
+        Self::with_clock(name, SystemClock)
+    }
+
+    /// Creates a new time tracker for the given project name, reading the
+    /// current time from the provided clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{
+    ///     clock::{Clock, MockClock},
+    ///     time_tracker::TimeTracker,
+    /// };
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("My Project", clock.clone());
+    /// assert_eq!(tracker.start(), clock.now());
+    ///
+    /// clock.advance(chrono::TimeDelta::minutes(1));
+    /// tracker.finish();
+    /// assert_eq!(tracker.elapsed(), chrono::TimeDelta::minutes(1));
+    /// ```
+    pub fn with_clock<S: ToString + ?Sized, C: Clock + 'static>(name: &S, clock: C) -> Self {
+        Self::with_shared_clock(name, Arc::new(clock))
+    }
+
+    /// Creates a new time tracker for the given project name, reading the
+    /// current time from the provided shared clock.
+    fn with_shared_clock<S: ToString + ?Sized>(name: &S, clock: Arc<dyn Clock>) -> Self {
+        Self { name: name.to_string(), tasks: Vec::new(), start: clock.now(), end: None, clock }
+    }
+
+    /// Creates a new, empty sub-tracker sharing the clock of the tracker.
+    fn new_sub_tracker<S: ToString + ?Sized>(&self, name: &S) -> Self {
+        Self::with_shared_clock(name, Arc::clone(&self.clock))
+    }
+
+    #[must_use]
+    /// Returns the clock the tracker reads the current time from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{
+    ///     clock::{Clock, MockClock},
+    ///     time_tracker::TimeTracker,
+    /// };
+    ///
+    /// let clock = MockClock::default();
+    /// let tracker = TimeTracker::with_clock("My Project", clock.clone());
+    /// assert_eq!(tracker.clock().now(), clock.now());
+    /// ```
+    pub fn clock(&self) -> &dyn Clock {
+        &*self.clock
+    }
+
+    /// Returns the shared clock the tracker reads the current time from.
+    pub(crate) fn shared_clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
+    #[must_use]
+    /// Starts a new task with the given name at the current time of the
+    /// clock of the tracker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{clock::MockClock, time_tracker::TimeTracker};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("My Project", clock.clone());
+    /// let task = tracker.start_task("Task");
+    /// clock.advance(chrono::TimeDelta::seconds(3));
+    /// tracker.add_completed_task(task);
+    /// assert_eq!(tracker.get("Task").unwrap().time(), chrono::TimeDelta::seconds(3));
+    /// ```
+    pub fn start_task<S: ToString + ?Sized>(&self, name: &S) -> Task {
+        Task::with_shared_clock(name, Arc::clone(&self.clock))
+    }
+
+    /// Returns an iterator over the direct sub-trackers of the tracker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::time_tracker::TimeTracker;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.extend(TimeTracker::new("Sub Project"));
+    ///
+    /// let names: Vec<&str> = tracker.sub_trackers().map(TimeTracker::name).collect();
+    /// assert_eq!(names, ["Sub Project"]);
+    /// ```
+    pub fn sub_trackers(&self) -> impl Iterator<Item = &TimeTracker> {
+        self.tasks.iter().filter_map(|entry| entry.sub_tracker.as_ref())
+    }
+
+    #[must_use]
+    /// Returns the sub-tracker which was summarized into the provided task,
+    /// if any.
+    ///
+    /// # Implementation Note
+    ///
+    /// The task must be a reference to one of the tasks of the tracker, as
+    /// returned by [`tasks`](Self::tasks), and not a copy of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{task::Task, time_tracker::TimeTracker};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// tracker.extend(TimeTracker::new("Sub Project"));
+    ///
+    /// let tasks: Vec<_> = tracker.tasks().collect();
+    /// assert!(tracker.sub_tracker_of(tasks[0]).is_none());
+    /// assert_eq!(tracker.sub_tracker_of(tasks[1]).unwrap().name(), "Sub Project");
+    /// ```
+    pub fn sub_tracker_of(&self, task: &CompletedTask) -> Option<&TimeTracker> {
+        self.tasks
+            .iter()
+            .find(|entry| std::ptr::eq(&raw const entry.task, task))
+            .and_then(|entry| entry.sub_tracker.as_ref())
+    }
+
+    /// Updates the tasks summarizing the sub-trackers, recursively, after
+    /// the sub-trackers were edited.
+    fn refresh(&mut self) {
+        for entry in &mut self.tasks {
+            if let Some(sub_tracker) = entry.sub_tracker.as_mut() {
+                sub_tracker.refresh();
+                entry.update_summary();
+            }
         }
     }
 
-    /// Returns the sub-trackers.
-    pub(crate) fn sub_trackers(&self) -> &[TimeTracker] {
-        &self.sub_trackers
+    /// Updates the tasks summarizing the sub-trackers along the provided
+    /// slash-separated path, deepest first, after the sub-tracker at the end
+    /// of the path was edited, leaving the other sub-trackers untouched.
+    fn refresh_path(&mut self, path: &str) {
+        if path.is_empty() {
+            return;
+        }
+        let (name, rest) = path.split_once(PATH_SEPARATOR).unwrap_or((path, ""));
+        if let Some(entry) = self.tasks.iter_mut().find(|entry| {
+            entry.sub_tracker.as_ref().is_some_and(|sub_tracker| sub_tracker.name == name)
+        }) {
+            if let Some(sub_tracker) = entry.sub_tracker.as_mut() {
+                sub_tracker.refresh_path(rest);
+            }
+            entry.update_summary();
+        }
     }
 
     /// Extends the tracker from another tracker.
     ///
+    /// The other tracker is added as a sub-tracker, summarized by a task
+    /// whose time is the wall-clock time covered by its tasks, so that
+    /// concurrent tasks are not counted more than once.
+    ///
     /// # Examples
     ///
     /// ```
@@ -62,11 +338,16 @@ impl TimeTracker {
     /// assert_eq!(tracker1.tasks().count(), initial_tasks + 1); // Adds to_completed_task of tracker2
     /// ```
     pub fn extend(&mut self, other: TimeTracker) {
-        self.tasks.push(other.clone().into());
-        self.sub_trackers.push(other);
+        self.tasks.push(other.into());
     }
 
-    /// Adds a task to the tracker.
+    /// Adds a task to the tracker: a task, or the name of a task, completed
+    /// at the current time of the clock of the tracker, or a task already
+    /// completed, recorded as it is.
+    ///
+    /// A task whose name is a slash-separated path, such as
+    /// `build/compile/crate_a`, is recorded under the sub-trackers named by
+    /// the leading components of the path, which are created if missing.
     ///
     /// # Examples
     ///
@@ -83,47 +364,128 @@ impl TimeTracker {
     /// let task2 = Task::new("Task 2");
     /// tracker.add_completed_task(task2);
     /// assert_eq!(tracker.tasks().count(), 2);
+    ///
+    /// // Slash-separated names create the sub-trackers.
+    /// tracker.add_completed_task("build/compile/crate_a");
+    /// tracker.add_completed_task("build/compile/crate_b");
+    /// assert_eq!(tracker.tasks().count(), 3);
+    /// assert_eq!(tracker.get_tracker("build/compile").unwrap().tasks().count(), 2);
+    /// assert_eq!(
+    ///     tracker.get("build").unwrap().time(),
+    ///     tracker.get_tracker("build").unwrap().tracked_time()
+    /// );
     /// ```
-    pub fn add_completed_task(&mut self, task: Task) {
-        self.tasks.push(task.into());
+    pub fn add_completed_task<T: IntoCompletedTask>(&mut self, task: T) {
+        self.record(task.into_completed_task(&self.clock), false);
     }
 
     /// Extends a previously completed task.
     ///
+    /// As with [`add_completed_task`](Self::add_completed_task), a task whose
+    /// name is a slash-separated path is recorded under the sub-trackers
+    /// named by the leading components of the path.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use std::{thread, time::Duration};
+    /// use chrono::TimeDelta;
+    /// use time_requirements::{clock::MockClock, time_tracker::TimeTracker};
     ///
-    /// use time_requirements::{task::Task, time_tracker::TimeTracker};
-    ///
-    /// let mut tracker = TimeTracker::new("Project");
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
     ///
     /// // Add new task
-    /// let task1 = Task::new("Task");
-    /// thread::sleep(Duration::from_millis(10));
+    /// let task1 = tracker.start_task("Task");
+    /// clock.advance(TimeDelta::milliseconds(10));
     /// tracker.add_or_extend_completed_task(task1);
     /// assert_eq!(tracker.tasks().count(), 1);
     ///
     /// // Extend existing task
-    /// let task2 = Task::new("Task"); // Same name
-    /// thread::sleep(Duration::from_millis(10));
+    /// let task2 = tracker.start_task("Task"); // Same name
+    /// clock.advance(TimeDelta::milliseconds(10));
     /// tracker.add_or_extend_completed_task(task2);
     /// assert_eq!(tracker.tasks().count(), 1); // Still one task, but extended
+    /// assert_eq!(tracker.get("Task").unwrap().time(), TimeDelta::milliseconds(20));
     ///
     /// // Add another new task
-    /// let task3 = Task::new("Another Task");
+    /// let task3 = tracker.start_task("Another Task");
     /// tracker.add_or_extend_completed_task(task3);
     /// assert_eq!(tracker.tasks().count(), 2);
+    ///
+    /// // Extend a task of a sub-tracker
+    /// tracker.add_or_extend_completed_task("build/compile");
+    /// tracker.add_or_extend_completed_task("build/compile");
+    /// assert_eq!(tracker.get_tracker("build").unwrap().tasks().count(), 1);
     /// ```
-    pub fn add_or_extend_completed_task(&mut self, task: Task) {
-        for existing_task in &mut self.tasks {
-            if existing_task.name() == task.name() {
-                existing_task.extend(&task.into());
-                return;
-            }
+    pub fn add_or_extend_completed_task<T: IntoCompletedTask>(&mut self, task: T) {
+        self.record(task.into_completed_task(&self.clock), true);
+    }
+
+    /// Adds a task to the tracker, completed with the provided outcome, which
+    /// may also be derived from a `Result`.
+    ///
+    /// As with [`add_completed_task`](Self::add_completed_task), a task whose
+    /// name is a slash-separated path is recorded under the sub-trackers
+    /// named by the leading components of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{outcome::Outcome, prelude::*};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task_with("Download", Outcome::skipped("Cached"));
+    ///
+    /// let task = Task::new("Parse");
+    /// let result = "42".parse::<u32>();
+    /// tracker.add_completed_task_with(task, &result);
+    ///
+    /// assert_eq!(tracker.get("Download").unwrap().outcome().status(), "skipped");
+    /// assert!(tracker.get("Parse").unwrap().outcome().is_success());
+    /// ```
+    pub fn add_completed_task_with<T: IntoCompletedTask, O: Into<Outcome>>(
+        &mut self,
+        task: T,
+        outcome: O,
+    ) {
+        let mut completed = task.into_completed_task(&self.clock);
+        completed.outcome = outcome.into();
+        self.record(completed, false);
+    }
+
+    /// Records the provided completed task, under the sub-trackers named by
+    /// the leading components of its name if it is a slash-separated path.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The task to record.
+    /// * `extend` - Whether to extend the task with the same name, if any,
+    ///   rather than adding a new one.
+    pub(crate) fn record(&mut self, mut task: CompletedTask, extend: bool) {
+        let parent = task.name.rfind(PATH_SEPARATOR).map(|index| {
+            let name = task.name.split_off(index + PATH_SEPARATOR.len_utf8());
+            let mut parent = std::mem::replace(&mut task.name, name);
+            parent.pop();
+            parent
+        });
+        let tracker = match &parent {
+            Some(parent) => self.get_or_create_tracker(parent, task.start),
+            None => &mut *self,
+        };
+
+        if let Some(entry) = tracker
+            .tasks
+            .iter_mut()
+            .find(|entry| extend && entry.sub_tracker.is_none() && entry.task.name() == task.name())
+        {
+            entry.task.extend(&task);
+        } else {
+            tracker.tasks.push(task.into());
+        }
+
+        if let Some(parent) = parent {
+            self.refresh_path(&parent);
         }
-        self.tasks.push(task.into());
     }
 
     #[must_use]
@@ -157,6 +519,263 @@ impl TimeTracker {
         self.start
     }
 
+    /// Marks the project as finished, setting its end time to now.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::time_tracker::TimeTracker;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// assert!(!tracker.is_finished());
+    /// tracker.finish();
+    /// assert!(tracker.is_finished());
+    /// assert!(tracker.end() >= tracker.start());
+    /// ```
+    pub fn finish(&mut self) {
+        self.end = Some(self.clock.now());
+    }
+
+    #[must_use]
+    /// Returns whether the project was explicitly finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::time_tracker::TimeTracker;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// assert!(!tracker.is_finished());
+    /// tracker.finish();
+    /// assert!(tracker.is_finished());
+    /// ```
+    pub fn is_finished(&self) -> bool {
+        self.end.is_some()
+    }
+
+    #[must_use]
+    /// Returns the end time of the project.
+    ///
+    /// # Implementation Note
+    ///
+    /// If the project was not explicitly finished, the end time is the
+    /// latest end time of its tasks, or its start time if it has no tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{task::Task, time_tracker::TimeTracker};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// assert_eq!(tracker.end(), tracker.start());
+    ///
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// let task_end = tracker.tasks().next().unwrap().end();
+    /// assert_eq!(tracker.end(), task_end);
+    ///
+    /// tracker.finish();
+    /// assert!(tracker.end() >= task_end);
+    /// ```
+    pub fn end(&self) -> chrono::NaiveDateTime {
+        self.end.unwrap_or_else(|| {
+            self.tasks().map(CompletedTask::end).max().unwrap_or(self.start).max(self.start)
+        })
+    }
+
+    #[must_use]
+    /// Returns the wall-clock time elapsed between the start and the end of
+    /// the project.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{clock::MockClock, time_tracker::TimeTracker};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// clock.advance(chrono::TimeDelta::milliseconds(10));
+    /// tracker.finish();
+    /// assert_eq!(tracker.elapsed(), chrono::TimeDelta::milliseconds(10));
+    /// ```
+    pub fn elapsed(&self) -> chrono::TimeDelta {
+        self.end() - self.start
+    }
+
+    /// Returns the union of the intervals during which the tasks were
+    /// running, looking through the tasks summarizing sub-trackers.
+    fn busy_intervals(&self) -> Vec<Interval> {
+        merge_intervals(self.tasks.iter().flat_map(Entry::busy_intervals).collect())
+    }
+
+    /// Returns the length of the union of the task intervals, optionally
+    /// clipped to the provided bounds.
+    fn covered_time(&self, bounds: Option<Interval>) -> chrono::TimeDelta {
+        self.busy_intervals()
+            .into_iter()
+            .map(|(task_start, task_end)| match bounds {
+                Some((start, end)) => (task_start.max(start), task_end.min(end)),
+                None => (task_start, task_end),
+            })
+            .filter(|(task_start, task_end)| task_start < task_end)
+            .map(|(task_start, task_end)| task_end - task_start)
+            .sum()
+    }
+
+    #[must_use]
+    /// Returns the wall-clock time covered by at least one task, i.e. the
+    /// length of the union of the task intervals, where the tasks summarizing
+    /// sub-trackers only cover the intervals of the tasks of the sub-trackers.
+    ///
+    /// # Implementation Note
+    ///
+    /// Differently from [`total_time`](Self::total_time), which sums the
+    /// durations of the tasks, time during which several tasks ran
+    /// concurrently is only counted once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use time_requirements::{clock::MockClock, time_tracker::TimeTracker};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let first = tracker.start_task("First");
+    /// let second = tracker.start_task("Second");
+    /// clock.advance(TimeDelta::milliseconds(10));
+    /// tracker.add_completed_task(first);
+    /// tracker.add_completed_task(second);
+    ///
+    /// // The two tasks ran concurrently, so their union is shorter than their sum.
+    /// assert_eq!(tracker.tracked_time(), TimeDelta::milliseconds(10));
+    /// assert_eq!(tracker.total_time(), TimeDelta::milliseconds(20));
+    /// assert!(tracker.tracked_time() <= tracker.elapsed());
+    /// ```
+    pub fn tracked_time(&self) -> chrono::TimeDelta {
+        self.covered_time(None)
+    }
+
+    #[must_use]
+    /// Returns the parallelism factor of the tasks, i.e. their total time
+    /// divided by the wall-clock time they cover, which is one when no tasks
+    /// ran concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{clock::MockClock, time_tracker::TimeTracker};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// assert!((tracker.parallelism() - 1.0).abs() < f64::EPSILON);
+    ///
+    /// let first = tracker.start_task("First");
+    /// let second = tracker.start_task("Second");
+    /// clock.advance(chrono::TimeDelta::milliseconds(10));
+    /// tracker.add_completed_task(first);
+    /// tracker.add_completed_task(second);
+    /// assert!((tracker.parallelism() - 2.0).abs() < f64::EPSILON);
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    pub fn parallelism(&self) -> f64 {
+        let (Some(total), Some(tracked)) =
+            (self.total_time().num_nanoseconds(), self.tracked_time().num_nanoseconds())
+        else {
+            return 1.0;
+        };
+        if tracked == 0 { 1.0 } else { total as f64 / tracked as f64 }
+    }
+
+    #[must_use]
+    /// Returns the peak number of tasks of the tracker running concurrently
+    /// while the provided task was running, including the task itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{clock::MockClock, time_tracker::TimeTracker};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let first = tracker.start_task("First");
+    /// let second = tracker.start_task("Second");
+    /// clock.advance(chrono::TimeDelta::milliseconds(10));
+    /// tracker.add_completed_task(first);
+    /// tracker.add_completed_task(second);
+    /// let task = tracker.start_task("Third");
+    /// clock.advance(chrono::TimeDelta::milliseconds(10));
+    /// tracker.add_completed_task(task);
+    ///
+    /// let tasks: Vec<_> = tracker.tasks().collect();
+    /// assert_eq!(tracker.concurrency_of(tasks[0]), 2);
+    /// assert_eq!(tracker.concurrency_of(tasks[2]), 1);
+    /// ```
+    pub fn concurrency_of(&self, task: &CompletedTask) -> usize {
+        let task_intervals = self
+            .tasks
+            .iter()
+            .find(|entry| std::ptr::eq(&raw const entry.task, task))
+            .map_or_else(|| vec![(task.start, task.end)], Entry::busy_intervals);
+        let task_intervals = &task_intervals;
+        let mut events: Vec<(chrono::NaiveDateTime, bool)> = self
+            .tasks
+            .iter()
+            .flat_map(Entry::busy_intervals)
+            .flat_map(|(start, end)| {
+                task_intervals
+                    .iter()
+                    .map(move |&(task_start, task_end)| (start.max(task_start), end.min(task_end)))
+            })
+            .filter(|(start, end)| start < end)
+            .flat_map(|(start, end)| [(start, true), (end, false)])
+            .collect();
+        // Ends sort before starts at the same instant, so that tasks running
+        // back to back are not considered concurrent.
+        events.sort_unstable();
+
+        let (mut running, mut peak) = (0_usize, 1_usize);
+        for (_, is_start) in events {
+            if is_start {
+                running += 1;
+                peak = peak.max(running);
+            } else {
+                running = running.saturating_sub(1);
+            }
+        }
+        peak
+    }
+
+    #[must_use]
+    /// Returns the wall-clock time of the project not covered by any task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{clock::MockClock, time_tracker::TimeTracker};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// // Some uninstrumented work.
+    /// clock.advance(chrono::TimeDelta::milliseconds(20));
+    /// let task = tracker.start_task("Task");
+    /// clock.advance(chrono::TimeDelta::milliseconds(5));
+    /// tracker.add_completed_task(task);
+    /// tracker.finish();
+    ///
+    /// assert_eq!(tracker.untracked_time(), chrono::TimeDelta::milliseconds(20));
+    /// assert_eq!(tracker.untracked_time(), tracker.elapsed() - tracker.tracked_time());
+    /// ```
+    pub fn untracked_time(&self) -> chrono::TimeDelta {
+        let covered_time = self.covered_time(Some((self.start, self.end())));
+        (self.elapsed() - covered_time).max(chrono::TimeDelta::zero())
+    }
+
+    /// Returns the task at the provided index, with the sub-tracker it
+    /// summarizes, if any.
+    pub(crate) fn entry(&self, index: usize) -> Option<(&CompletedTask, Option<&TimeTracker>)> {
+        self.tasks.get(index).map(|entry| (&entry.task, entry.sub_tracker.as_ref()))
+    }
+
     /// Iterates the task from the tracker.
     ///
     /// # Examples
@@ -182,7 +801,7 @@ impl TimeTracker {
     /// assert_eq!(tasks[1].name(), "Task 2");
     /// ```
     pub fn tasks(&self) -> impl Iterator<Item = &CompletedTask> {
-        self.tasks.iter()
+        self.tasks.iter().map(|entry| &entry.task)
     }
 
     #[must_use]
@@ -191,35 +810,36 @@ impl TimeTracker {
     /// # Examples
     ///
     /// ```
-    /// use std::{thread, time::Duration};
+    /// use chrono::TimeDelta;
+    /// use time_requirements::{clock::MockClock, time_tracker::TimeTracker};
     ///
-    /// use time_requirements::{task::Task, time_tracker::TimeTracker};
+    /// let clock = MockClock::default();
     ///
     /// // No tasks
-    /// let tracker = TimeTracker::new("Project");
+    /// let tracker = TimeTracker::with_clock("Project", clock.clone());
     /// assert!(tracker.slowest_task().is_none());
     ///
     /// // One task
-    /// let mut tracker = TimeTracker::new("Project");
-    /// let task = Task::new("Only task");
-    /// thread::sleep(Duration::from_millis(10));
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let task = tracker.start_task("Only task");
+    /// clock.advance(TimeDelta::milliseconds(10));
     /// tracker.add_completed_task(task);
     /// assert_eq!(tracker.slowest_task().unwrap().name(), "Only task");
     ///
     /// // Multiple tasks
-    /// let mut tracker = TimeTracker::new("Project");
-    /// let task1 = Task::new("Short");
-    /// thread::sleep(Duration::from_millis(10));
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let task1 = tracker.start_task("Short");
+    /// clock.advance(TimeDelta::milliseconds(10));
     /// tracker.add_completed_task(task1);
     ///
-    /// let task2 = Task::new("Long");
-    /// thread::sleep(Duration::from_millis(100));
+    /// let task2 = tracker.start_task("Long");
+    /// clock.advance(TimeDelta::milliseconds(100));
     /// tracker.add_completed_task(task2);
     ///
     /// assert_eq!(tracker.slowest_task().unwrap().name(), "Long");
     /// ```
     pub fn slowest_task(&self) -> Option<&CompletedTask> {
-        self.tasks.iter().max()
+        self.tasks().max()
     }
 
     #[must_use]
@@ -228,29 +848,353 @@ impl TimeTracker {
     /// # Examples
     ///
     /// ```
-    /// use std::{thread, time::Duration};
+    /// use chrono::TimeDelta;
+    /// use time_requirements::{clock::MockClock, time_tracker::TimeTracker};
     ///
-    /// use time_requirements::{task::Task, time_tracker::TimeTracker};
+    /// let clock = MockClock::default();
     ///
     /// // Empty tracker
-    /// let tracker = TimeTracker::new("Project");
+    /// let tracker = TimeTracker::with_clock("Project", clock.clone());
     /// assert_eq!(tracker.total_time().num_milliseconds(), 0);
     ///
     /// // Tracker with tasks
-    /// let mut tracker = TimeTracker::new("Project");
-    /// let task1 = Task::new("Task 1");
-    /// thread::sleep(Duration::from_millis(50));
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let task1 = tracker.start_task("Task 1");
+    /// clock.advance(TimeDelta::milliseconds(50));
     /// tracker.add_completed_task(task1);
     ///
-    /// let task2 = Task::new("Task 2");
-    /// thread::sleep(Duration::from_millis(30));
+    /// let task2 = tracker.start_task("Task 2");
+    /// clock.advance(TimeDelta::milliseconds(30));
     /// tracker.add_completed_task(task2);
     ///
-    /// let total = tracker.total_time();
-    /// assert!(total.num_milliseconds() >= 80); // At least 80ms
+    /// assert_eq!(tracker.total_time(), TimeDelta::milliseconds(80));
     /// ```
     pub fn total_time(&self) -> chrono::TimeDelta {
-        self.tasks.iter().map(CompletedTask::time).sum()
+        self.tasks().map(CompletedTask::time).sum()
+    }
+
+    #[must_use]
+    /// Returns the time spent on the tasks of the tracker itself, excluding
+    /// the time spent in its sub-trackers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{clock::MockClock, time_tracker::TimeTracker};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// let task = tracker.start_task("Own Task");
+    /// clock.advance(chrono::TimeDelta::milliseconds(10));
+    /// tracker.add_completed_task(task);
+    ///
+    /// let mut sub_tracker = TimeTracker::with_clock("Sub Project", clock.clone());
+    /// let task = sub_tracker.start_task("Sub Task");
+    /// clock.advance(chrono::TimeDelta::milliseconds(10));
+    /// sub_tracker.add_completed_task(task);
+    /// let sub_time = sub_tracker.total_time();
+    /// tracker.extend(sub_tracker);
+    ///
+    /// assert_eq!(tracker.self_time(), tracker.total_time() - sub_time);
+    /// assert!(tracker.self_time() < tracker.total_time());
+    /// ```
+    pub fn self_time(&self) -> chrono::TimeDelta {
+        self.tasks
+            .iter()
+            .filter(|entry| entry.sub_tracker.is_none())
+            .map(|entry| entry.task.time())
+            .sum()
+    }
+
+    #[must_use]
+    /// Returns the self time of the provided task of the tracker: the time of
+    /// the task itself, or the self time of the sub-tracker it summarizes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{task::Task, time_tracker::TimeTracker};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// let mut sub_tracker = TimeTracker::new("Sub Project");
+    /// sub_tracker.add_completed_task(Task::new("Sub Task"));
+    /// let mut nested_tracker = TimeTracker::new("Nested Project");
+    /// nested_tracker.add_completed_task(Task::new("Nested Task"));
+    /// sub_tracker.extend(nested_tracker);
+    /// let sub_self_time = sub_tracker.self_time();
+    /// tracker.extend(sub_tracker);
+    ///
+    /// let tasks: Vec<_> = tracker.tasks().collect();
+    /// assert_eq!(tracker.task_self_time(tasks[0]), tasks[0].time());
+    /// assert_eq!(tracker.task_self_time(tasks[1]), sub_self_time);
+    /// ```
+    pub fn task_self_time(&self, task: &CompletedTask) -> chrono::TimeDelta {
+        self.sub_tracker_of(task).map_or_else(|| task.time(), TimeTracker::self_time)
+    }
+
+    #[must_use]
+    /// Returns the number of tasks with each outcome across the hierarchy,
+    /// excluding the tasks summarizing sub-trackers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{outcome::Outcome, prelude::*};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task("Fetch");
+    /// tracker.add_completed_task_with("build/Compile", Outcome::failure("Syntax error"));
+    /// tracker.add_completed_task_with("build/Link", Outcome::skipped("Nothing to link"));
+    ///
+    /// let counts = tracker.outcome_counts();
+    /// assert_eq!((counts.success, counts.failure, counts.skipped), (1, 1, 1));
+    /// ```
+    pub fn outcome_counts(&self) -> OutcomeCounts {
+        let mut counts = OutcomeCounts::default();
+        for entry in &self.tasks {
+            match &entry.sub_tracker {
+                Some(sub_tracker) => {
+                    let sub_counts = sub_tracker.outcome_counts();
+                    counts.success += sub_counts.success;
+                    counts.failure += sub_counts.failure;
+                    counts.panicked += sub_counts.panicked;
+                    counts.cancelled += sub_counts.cancelled;
+                    counts.skipped += sub_counts.skipped;
+                }
+                None => counts.add(&entry.task.outcome),
+            }
+        }
+        counts
+    }
+
+    #[must_use]
+    /// Returns the outcome of the tracker as a whole: a failure if any task
+    /// of the hierarchy failed or panicked, and a success otherwise.
+    ///
+    /// The tasks summarizing the sub-trackers take this outcome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{outcome::Outcome, prelude::*};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task("build/Fetch");
+    /// assert!(tracker.outcome().is_success());
+    ///
+    /// tracker.add_completed_task_with("build/Compile", Outcome::failure("Syntax error"));
+    /// assert_eq!(tracker.outcome(), Outcome::failure("1 of 2 tasks failed"));
+    /// assert!(tracker.get("build").unwrap().outcome().is_failure());
+    /// ```
+    pub fn outcome(&self) -> Outcome {
+        let counts = self.outcome_counts();
+        let failed = counts.failure + counts.panicked;
+        if failed == 0 {
+            Outcome::Success
+        } else {
+            Outcome::failure(&format!("{failed} of {} tasks failed", counts.total()))
+        }
+    }
+
+    /// Returns the time spent on the tasks of the hierarchy with outcomes
+    /// matching the provided predicate, excluding the tasks summarizing
+    /// sub-trackers.
+    fn time_by_outcome(&self, predicate: fn(&Outcome) -> bool) -> chrono::TimeDelta {
+        self.tasks
+            .iter()
+            .map(|entry| match &entry.sub_tracker {
+                Some(sub_tracker) => sub_tracker.time_by_outcome(predicate),
+                None if predicate(&entry.task.outcome) => entry.task.time(),
+                None => chrono::TimeDelta::zero(),
+            })
+            .sum()
+    }
+
+    #[must_use]
+    /// Returns the time spent on the successful tasks of the hierarchy,
+    /// excluding the tasks summarizing sub-trackers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{outcome::Outcome, prelude::*};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task("Fetch");
+    /// tracker.add_completed_task_with("Compile", Outcome::failure("Syntax error"));
+    ///
+    /// assert_eq!(tracker.successful_time() + tracker.failed_time(), tracker.total_time());
+    /// assert_eq!(tracker.failed_time(), tracker.get("Compile").unwrap().time());
+    /// ```
+    pub fn successful_time(&self) -> chrono::TimeDelta {
+        self.time_by_outcome(Outcome::is_success)
+    }
+
+    #[must_use]
+    /// Returns the time spent on the failed tasks of the hierarchy, including
+    /// those which panicked, excluding the tasks summarizing sub-trackers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{outcome::Outcome, prelude::*};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task_with("Compile", Outcome::failure("Syntax error"));
+    /// tracker.add_completed_task_with("Link", Outcome::cancelled("Interrupted"));
+    ///
+    /// assert_eq!(tracker.failed_time(), tracker.get("Compile").unwrap().time());
+    /// ```
+    pub fn failed_time(&self) -> chrono::TimeDelta {
+        self.time_by_outcome(Outcome::is_failure)
+    }
+
+    #[must_use]
+    /// Returns the CPU time consumed by the tasks of the hierarchy which
+    /// measured it, or `None` if none did.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task("Wait");
+    /// assert!(tracker.cpu_time().is_none());
+    ///
+    /// let task = tracker.start_task("build/Compile").with_cpu_time();
+    /// let _sum: u64 = (0..1_000_000).sum();
+    /// tracker.add_completed_task(task);
+    ///
+    /// assert_eq!(tracker.cpu_time(), tracker.get("build/Compile").unwrap().cpu_time());
+    /// assert_eq!(tracker.cpu_time(), tracker.get("build").unwrap().cpu_time());
+    /// ```
+    pub fn cpu_time(&self) -> Option<CpuTime> {
+        self.tasks().filter_map(CompletedTask::cpu_time).reduce(|total, cpu| total + cpu)
+    }
+
+    #[must_use]
+    /// Returns the memory used by the process across the tasks of the
+    /// hierarchy which measured it, from the start of the first one to the
+    /// end of the last one, or `None` if none did.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task("Wait");
+    /// assert!(tracker.memory_usage().is_none());
+    ///
+    /// let task = tracker.start_task("load/Spectra").with_memory_usage();
+    /// let spectra = vec![0.0_f64; 1024 * 1024];
+    /// tracker.add_completed_task(task);
+    /// drop(spectra);
+    ///
+    /// assert_eq!(tracker.memory_usage(), tracker.get("load").unwrap().memory_usage());
+    /// ```
+    pub fn memory_usage(&self) -> Option<MemoryUsage> {
+        self.tasks().filter_map(CompletedTask::memory_usage).reduce(MemoryUsage::then)
+    }
+
+    #[must_use]
+    /// Returns the allocations performed by the tasks of the hierarchy which
+    /// counted them, or `None` if none did.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{allocations::CountingAllocator, prelude::*};
+    ///
+    /// #[global_allocator]
+    /// static ALLOCATOR: CountingAllocator = CountingAllocator::new(std::alloc::System);
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task("Wait");
+    /// assert!(tracker.allocations().is_none());
+    ///
+    /// let task = tracker.start_task("parse/Spectra").with_allocations();
+    /// let spectra: Vec<Vec<f64>> = (0..10).map(|_| vec![0.0; 100]).collect();
+    /// tracker.add_completed_task(task);
+    /// drop(spectra);
+    ///
+    /// assert!(tracker.allocations().unwrap().allocations >= 10);
+    /// assert_eq!(tracker.allocations(), tracker.get("parse").unwrap().allocations());
+    /// ```
+    pub fn allocations(&self) -> Option<AllocationCounts> {
+        self.tasks()
+            .filter_map(CompletedTask::allocations)
+            .reduce(|total, allocations| total + allocations)
+    }
+
+    #[must_use]
+    /// Returns the I/O performed by the tasks of the hierarchy which
+    /// measured it, or `None` if none did.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task("Wait");
+    /// assert!(tracker.io_counters().is_none());
+    ///
+    /// let task = tracker.start_task("load/Manifest").with_io_counters();
+    /// let _manifest = std::fs::read_to_string("Cargo.toml").unwrap();
+    /// tracker.add_completed_task(task);
+    ///
+    /// assert_eq!(tracker.io_counters(), tracker.get("load").unwrap().io_counters());
+    /// ```
+    pub fn io_counters(&self) -> Option<IoCounters> {
+        self.tasks().filter_map(CompletedTask::io_counters).reduce(|total, io| total + io)
+    }
+
+    #[must_use]
+    /// Returns the time spent inside the reads and writes recorded by the
+    /// tasks of the hierarchy which recorded it, or `None` if none did.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{prelude::*, timed_io::TimedWriter};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task("Wait");
+    /// assert!(tracker.io_time().is_none());
+    ///
+    /// TimedWriter::new(std::io::sink(), "export/write", &mut tracker).finish();
+    ///
+    /// assert_eq!(tracker.io_time(), tracker.get("export").unwrap().io_time());
+    /// ```
+    pub fn io_time(&self) -> Option<chrono::TimeDelta> {
+        self.tasks().filter_map(CompletedTask::io_time).reduce(|total, time| total + time)
+    }
+
+    #[must_use]
+    /// Returns the context switches and page faults counted by the tasks of
+    /// the hierarchy which counted them, or `None` if none did.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task("Wait");
+    /// assert!(tracker.scheduling_events().is_none());
+    ///
+    /// let task = tracker.start_task("build/Link").with_scheduling_events();
+    /// tracker.add_completed_task(task);
+    ///
+    /// assert_eq!(tracker.scheduling_events(), tracker.get("build").unwrap().scheduling_events());
+    /// ```
+    pub fn scheduling_events(&self) -> Option<SchedulingEvents> {
+        self.tasks()
+            .filter_map(CompletedTask::scheduling_events)
+            .reduce(|total, events| total + events)
     }
 
     /// Saves the report as a JSON in the provided directory.
@@ -267,15 +1211,24 @@ impl TimeTracker {
     /// # Examples
     ///
     /// ```
-    /// use std::path::Path;
+    /// use std::{path::Path, sync::Arc};
     ///
-    /// use time_requirements::time_tracker::TimeTracker;
+    /// use time_requirements::prelude::*;
     ///
-    /// let tracker = TimeTracker::new("Project");
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let task = Task::new("Parse").with_tag("io").with_attribute("input", "spectra.mgf");
+    /// tracker.add_completed_task(task);
     /// let temp_dir = std::env::temp_dir();
     /// tracker.save(&temp_dir).expect("Failed to save");
     /// let file_path = temp_dir.join("Project.json");
     /// assert!(file_path.exists());
+    ///
+    /// // The metadata of the tasks is saved as well.
+    /// let saved: TimeTracker =
+    ///     serde_json::from_str(&std::fs::read_to_string(&file_path).unwrap()).unwrap();
+    /// let task = saved.get("Parse").unwrap();
+    /// assert!(task.metadata().has_tag("io"));
+    /// assert_eq!(task.metadata().attribute("input"), Some("spectra.mgf"));
     /// std::fs::remove_file(file_path).ok(); // Clean up
     /// ```
     pub fn save(&self, directory: &std::path::Path) -> std::io::Result<()> {
@@ -297,26 +1250,19 @@ impl TimeTracker {
     /// # Examples
     ///
     /// ```rust
-    /// use std::{path::Path, thread, time::Duration};
-    ///
-    /// use time_requirements::prelude::*;
+    /// use time_requirements::{clock::MockClock, prelude::*};
     ///
     /// // Create current tracker with tasks and sub-tracker
-    /// let mut current_tracker = TimeTracker::new("Current Project");
-    /// let task = Task::new("Main Task");
-    /// thread::sleep(Duration::from_millis(100));
+    /// let clock = MockClock::default();
+    /// let mut current_tracker = TimeTracker::with_clock("Current Project", clock.clone());
+    /// let task = current_tracker.start_task("Main Task");
+    /// clock.advance(chrono::TimeDelta::milliseconds(100));
     /// current_tracker.add_completed_task(task);
     ///
     /// // Add a sub-tracker
-    /// let sub_tracker = TimeTracker::new("Sub Project");
+    /// let sub_tracker = TimeTracker::with_clock("Sub Project", clock.clone());
     /// current_tracker.extend(sub_tracker);
     ///
-    /// // Create previous tracker with same task time
-    /// let mut previous_tracker = TimeTracker::new("Previous Project");
-    /// let prev_task = Task::new("Main Task");
-    /// thread::sleep(Duration::from_millis(100)); // Same time
-    /// previous_tracker.add_completed_task(prev_task);
-    ///
     /// let temp_path = std::env::temp_dir().join("test_report.md");
     /// current_tracker.write(&temp_path).expect("Failed to write report");
     /// assert!(temp_path.exists());
@@ -330,12 +1276,30 @@ impl TimeTracker {
     }
 }
 
-impl From<TimeTracker> for CompletedTask {
-    fn from(tracker: TimeTracker) -> Self {
+impl From<&TimeTracker> for CompletedTask {
+    fn from(tracker: &TimeTracker) -> Self {
+        let start = tracker.tasks().map(CompletedTask::start).min().unwrap_or(tracker.start);
         CompletedTask {
             name: tracker.name.clone(),
-            start: tracker.start,
-            end: tracker.start + tracker.total_time(),
+            start,
+            end: tracker.tasks().map(CompletedTask::end).max().unwrap_or(start).max(start),
+            busy_time: Some(tracker.tracked_time()),
+            io_time: tracker.io_time(),
+            metadata: Metadata::default(),
+            items: None,
+            processed_bytes: None,
+            outcome: tracker.outcome(),
+            cpu: tracker.cpu_time(),
+            memory: tracker.memory_usage(),
+            allocations: tracker.allocations(),
+            io: tracker.io_counters(),
+            scheduling: tracker.scheduling_events(),
         }
     }
 }
+
+impl From<TimeTracker> for CompletedTask {
+    fn from(tracker: TimeTracker) -> Self {
+        CompletedTask::from(&tracker)
+    }
+}
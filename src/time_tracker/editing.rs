@@ -0,0 +1,317 @@
+//! Submodule defining the editing API of a tracker, removing, renaming,
+//! moving and grouping tasks and sub-trackers by their slash-separated path.
+//!
+//! After each edit, the tasks summarizing the edited sub-trackers are updated,
+//! so that they keep matching the time tracked by the sub-trackers.
+
+use super::{Entry, TimeTracker};
+use crate::{
+    hierarchy::{PATH_SEPARATOR, matches_glob},
+    task::CompletedTask,
+};
+
+/// Splits the provided path into the path of the parent tracker and the name
+/// of the task.
+fn split(path: &str) -> (&str, &str) {
+    path.rsplit_once(PATH_SEPARATOR).unwrap_or(("", path))
+}
+
+impl TimeTracker {
+    /// Returns a mutable reference to the sub-tracker at the provided path,
+    /// where the empty path is the tracker itself.
+    fn get_tracker_mut(&mut self, path: &str) -> Option<&mut TimeTracker> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        path.split(PATH_SEPARATOR).try_fold(self, |tracker, name| {
+            tracker
+                .tasks
+                .iter_mut()
+                .filter_map(|entry| entry.sub_tracker.as_mut())
+                .find(|sub_tracker| sub_tracker.name == name)
+        })
+    }
+
+    /// Returns a mutable reference to the sub-tracker at the provided path,
+    /// creating the missing sub-trackers along the way.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the sub-tracker, where the empty path is the
+    ///   tracker itself.
+    /// * `start` - The start of the task about to be added to the
+    ///   sub-tracker, which the sub-trackers along the path are extended to
+    ///   cover.
+    pub(crate) fn get_or_create_tracker(
+        &mut self,
+        path: &str,
+        start: chrono::NaiveDateTime,
+    ) -> &mut TimeTracker {
+        if path.is_empty() {
+            return self;
+        }
+        path.split(PATH_SEPARATOR).fold(self, |tracker, name| {
+            let index = tracker
+                .tasks
+                .iter()
+                .position(|entry| {
+                    entry.sub_tracker.as_ref().is_some_and(|sub_tracker| sub_tracker.name == name)
+                })
+                .unwrap_or_else(|| {
+                    let mut sub_tracker = tracker.new_sub_tracker(name);
+                    sub_tracker.start = start;
+                    tracker.tasks.push(sub_tracker.into());
+                    tracker.tasks.len() - 1
+                });
+            let sub_tracker = tracker.tasks[index]
+                .sub_tracker
+                .as_mut()
+                .expect("the entry was selected because it summarizes a sub-tracker");
+            sub_tracker.start = sub_tracker.start.min(start);
+            sub_tracker
+        })
+    }
+
+    /// Removes the entry at the provided path, without updating the
+    /// summaries of the parent trackers.
+    fn take(&mut self, path: &str) -> Option<Entry> {
+        let (parent, name) = split(path);
+        let tracker = self.get_tracker_mut(parent)?;
+        let index = tracker.tasks.iter().position(|entry| entry.task.name == name)?;
+        Some(tracker.tasks.remove(index))
+    }
+
+    /// Removes the task at the provided slash-separated path, along with the
+    /// sub-tracker it summarizes, if any, and returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut sub_tracker = TimeTracker::new("Sub Project");
+    /// sub_tracker.add_completed_task(Task::new("Sub Task"));
+    /// sub_tracker.add_completed_task(Task::new("Other Sub Task"));
+    /// tracker.extend(sub_tracker);
+    ///
+    /// let removed = tracker.remove("Sub Project/Sub Task").unwrap();
+    /// assert_eq!(removed.name(), "Sub Task");
+    /// assert!(tracker.get("Sub Project/Sub Task").is_none());
+    /// assert_eq!(
+    ///     tracker.get("Sub Project").unwrap().time(),
+    ///     tracker.get_tracker("Sub Project").unwrap().tracked_time()
+    /// );
+    ///
+    /// assert!(tracker.remove("Sub Project").is_some());
+    /// assert_eq!(tracker.sub_trackers().count(), 0);
+    /// assert!(tracker.remove("Missing").is_none());
+    /// ```
+    pub fn remove(&mut self, path: &str) -> Option<CompletedTask> {
+        let entry = self.take(path)?;
+        self.refresh_path(split(path).0);
+        Some(entry.task)
+    }
+
+    /// Renames the task at the provided slash-separated path, along with the
+    /// sub-tracker it summarizes, if any, and returns whether it was renamed.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the task to rename.
+    /// * `name` - The new name, which must not contain the path separator
+    ///   nor be the name of another task of the same tracker, so that the
+    ///   paths stay unambiguous.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut sub_tracker = TimeTracker::new("Sub Project");
+    /// sub_tracker.add_completed_task(Task::new("Sub Task"));
+    /// tracker.extend(sub_tracker);
+    ///
+    /// assert!(tracker.rename("Sub Project", "Renamed"));
+    /// assert_eq!(tracker.get_tracker("Renamed").unwrap().name(), "Renamed");
+    /// assert!(tracker.get("Renamed/Sub Task").is_some());
+    ///
+    /// assert!(!tracker.rename("Renamed", "In/Valid"));
+    /// assert!(!tracker.rename("Missing", "Renamed"));
+    ///
+    /// tracker.add_completed_task(Task::new("Other Task"));
+    /// assert!(!tracker.rename("Other Task", "Renamed"));
+    /// assert!(tracker.get("Other Task").is_some());
+    /// ```
+    pub fn rename(&mut self, path: &str, name: &str) -> bool {
+        if name.contains(PATH_SEPARATOR) {
+            return false;
+        }
+        let (parent, old_name) = split(path);
+        let Some(tracker) = self.get_tracker_mut(parent) else {
+            return false;
+        };
+        let Some(index) = tracker.tasks.iter().position(|entry| entry.task.name == old_name) else {
+            return false;
+        };
+        if tracker
+            .tasks
+            .iter()
+            .enumerate()
+            .any(|(other, entry)| other != index && entry.task.name == name)
+        {
+            return false;
+        }
+        let entry = &mut tracker.tasks[index];
+        name.clone_into(&mut entry.task.name);
+        if let Some(sub_tracker) = entry.sub_tracker.as_mut() {
+            name.clone_into(&mut sub_tracker.name);
+        }
+        true
+    }
+
+    /// Moves the task at the provided slash-separated path, along with the
+    /// sub-tracker it summarizes, if any, to the end of the sub-tracker at
+    /// the destination path, and returns whether it was moved.
+    ///
+    /// The sub-trackers missing along the destination path are created, while
+    /// a sub-tracker cannot be moved into itself or into its own sub-trackers.
+    /// To keep the paths unambiguous, a sub-tracker cannot be moved next to a
+    /// task with the same name, nor a task next to a sub-tracker with the
+    /// same name, while tasks with the same name can be.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the task to move.
+    /// * `destination` - The path of the destination tracker, where the empty
+    ///   path is the tracker itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("Task"));
+    /// tracker.extend(TimeTracker::new("Sub Project"));
+    ///
+    /// assert!(tracker.move_task("Task", "Sub Project/Nested Project"));
+    /// assert!(tracker.get("Task").is_none());
+    /// assert!(tracker.get("Sub Project/Nested Project/Task").is_some());
+    /// assert_eq!(
+    ///     tracker.get("Sub Project").unwrap().time(),
+    ///     tracker.get_tracker("Sub Project").unwrap().tracked_time()
+    /// );
+    ///
+    /// assert!(!tracker.move_task("Sub Project", "Sub Project/Nested Project"));
+    /// assert!(!tracker.move_task("Missing", ""));
+    ///
+    /// tracker.add_completed_task(Task::new("Nested Project"));
+    /// assert!(!tracker.move_task("Nested Project", "Sub Project"));
+    /// ```
+    pub fn move_task(&mut self, path: &str, destination: &str) -> bool {
+        if destination == path
+            || destination.strip_prefix(path).is_some_and(|rest| rest.starts_with(PATH_SEPARATOR))
+        {
+            return false;
+        }
+        let (parent, name) = split(path);
+        let Some((index, is_sub_tracker)) = self.get_tracker_mut(parent).and_then(|tracker| {
+            tracker
+                .tasks
+                .iter()
+                .position(|entry| entry.task.name == name)
+                .map(|index| (index, tracker.tasks[index].sub_tracker.is_some()))
+        }) else {
+            return false;
+        };
+        let same_tracker = destination == parent;
+        if self.get_tracker_mut(destination).is_some_and(|tracker| {
+            tracker.tasks.iter().enumerate().any(|(other, entry)| {
+                !(same_tracker && other == index)
+                    && entry.task.name == name
+                    && (is_sub_tracker || entry.sub_tracker.is_some())
+            })
+        }) {
+            return false;
+        }
+        let Some(entry) = self.take(path) else {
+            return false;
+        };
+        self.get_or_create_tracker(destination, entry.task.start).tasks.push(entry);
+        self.refresh_path(split(path).0);
+        self.refresh_path(destination);
+        true
+    }
+
+    /// Groups the direct tasks of the sub-tracker at the provided path whose
+    /// name matches the provided glob pattern into a new sub-tracker, placed
+    /// where the first of them was, and returns the number of grouped tasks.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the tracker whose tasks are grouped, where the
+    ///   empty path is the tracker itself.
+    /// * `pattern` - The glob pattern, as accepted by [`matches_glob`],
+    ///   selecting the tasks.
+    /// * `name` - The name of the new sub-tracker, which must not contain the
+    ///   path separator nor be the name of a task left out of the group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::prelude::*;
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// tracker.add_completed_task(Task::new("setup"));
+    /// tracker.add_completed_task(Task::new("load batch 1"));
+    /// tracker.add_completed_task(Task::new("load batch 2"));
+    /// tracker.add_completed_task(Task::new("write"));
+    ///
+    /// assert_eq!(tracker.group("", "load batch *", "Loading"), 2);
+    /// let names: Vec<&str> = tracker.tasks().map(|task| task.name()).collect();
+    /// assert_eq!(names, ["setup", "Loading", "write"]);
+    /// assert!(tracker.get("Loading/load batch 2").is_some());
+    ///
+    /// assert_eq!(tracker.group("", "missing *", "Missing"), 0);
+    ///
+    /// // The new sub-tracker must be reachable by path and unambiguous.
+    /// assert_eq!(tracker.group("", "w*", "Writing/Output"), 0);
+    /// assert_eq!(tracker.group("", "w*", "setup"), 0);
+    /// assert!(tracker.get("write").is_some());
+    /// ```
+    pub fn group(&mut self, path: &str, pattern: &str, name: &str) -> usize {
+        if name.contains(PATH_SEPARATOR) {
+            return 0;
+        }
+        let Some(tracker) = self.get_tracker_mut(path) else {
+            return 0;
+        };
+        if tracker
+            .tasks
+            .iter()
+            .any(|entry| entry.task.name == name && !matches_glob(pattern, &entry.task.name))
+        {
+            return 0;
+        }
+        let Some(position) =
+            tracker.tasks.iter().position(|entry| matches_glob(pattern, &entry.task.name))
+        else {
+            return 0;
+        };
+        let (grouped, kept): (Vec<Entry>, Vec<Entry>) = std::mem::take(&mut tracker.tasks)
+            .into_iter()
+            .partition(|entry| matches_glob(pattern, &entry.task.name));
+
+        let mut group = tracker.new_sub_tracker(name);
+        group.start = grouped.iter().map(|entry| entry.task.start).min().unwrap_or(group.start);
+        group.tasks = grouped;
+        let count = group.tasks.len();
+
+        tracker.tasks = kept;
+        tracker.tasks.insert(position, group.into());
+        self.refresh();
+        count
+    }
+}
@@ -0,0 +1,396 @@
+//! Submodule defining the readers and writers recording the time spent inside
+//! their `read` and `write` calls and the bytes they moved, so that the time
+//! spent on I/O can be told apart from the time spent processing the data.
+
+use std::io::{self, Read, Write};
+
+use chrono::TimeDelta;
+
+use crate::{outcome::Outcome, task::Task, time_tracker::TimeTracker};
+
+/// The time and bytes accumulated by a timed reader or writer, recorded into
+/// the tracker as a task when dropped.
+struct IoTimer<'a> {
+    /// The tracker recording the task.
+    tracker: &'a mut TimeTracker,
+    /// The task started when the timer was created, taken when recorded.
+    task: Option<Task>,
+    /// The time spent inside the I/O calls.
+    time: TimeDelta,
+    /// The bytes moved by the I/O calls.
+    bytes: u64,
+}
+
+impl<'a> IoTimer<'a> {
+    /// Creates a new timer recording a task with the provided name.
+    fn new<S: ToString + ?Sized>(name: &S, tracker: &'a mut TimeTracker) -> Self {
+        let task = tracker.start_task(name);
+        Self { tracker, task: Some(task), time: TimeDelta::zero(), bytes: 0 }
+    }
+
+    /// Runs the provided I/O call, adding its time and the bytes it moved.
+    fn measure(&mut self, call: impl FnOnce() -> io::Result<usize>) -> io::Result<usize> {
+        let start = self.tracker.clock().now();
+        let result = call();
+        self.time += self.tracker.clock().now() - start;
+        if let Ok(bytes) = result {
+            self.bytes += bytes as u64;
+        }
+        result
+    }
+
+    /// Runs the provided I/O call moving no bytes, adding its time.
+    fn measure_unit(&mut self, call: impl FnOnce() -> io::Result<()>) -> io::Result<()> {
+        self.measure(|| call().map(|()| 0)).map(|_| ())
+    }
+}
+
+impl Drop for IoTimer<'_> {
+    fn drop(&mut self) {
+        let Some(task) = self.task.take() else {
+            return;
+        };
+        let mut completed = task.complete_with_bytes(self.bytes);
+        completed.io_time = Some(self.time);
+        if std::thread::panicking() {
+            completed.outcome = Outcome::Panicked { message: None };
+        }
+        self.tracker.record(completed, false);
+    }
+}
+
+/// A reader recording the time spent inside its `read` calls and the bytes
+/// read as a task, when finished or dropped.
+///
+/// The task runs from the creation of the reader until it is finished or
+/// dropped, and records the time spent inside the reads as its
+/// [I/O time](crate::task::CompletedTask::io_time), so that the report shows
+/// how much of a step is spent reading as opposed to processing what was
+/// read, next to the bytes read. As with
+/// [`add_completed_task`](TimeTracker::add_completed_task), a slash-separated
+/// name records the task under the named sub-trackers.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Read;
+///
+/// use time_requirements::{prelude::*, timed_io::TimedReader};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// let mut reader = TimedReader::new(&b"name,mass\nwater,18.01\n"[..], "load/read", &mut tracker);
+/// let mut contents = String::new();
+/// reader.read_to_string(&mut contents).unwrap();
+/// reader.finish();
+///
+/// let rows = tracker.start_task("load/parse");
+/// assert_eq!(contents.lines().count(), 2);
+/// tracker.add_completed_task(rows);
+///
+/// assert_eq!(tracker.get("load/read").unwrap().processed_bytes(), Some(22));
+/// assert_eq!(tracker.get_tracker("load").unwrap().tasks().count(), 2);
+/// ```
+pub struct TimedReader<'a, R> {
+    /// The wrapped reader.
+    inner: R,
+    /// The timer accumulating the time and bytes of the reads.
+    timer: IoTimer<'a>,
+}
+
+impl<'a, R: Read> TimedReader<'a, R> {
+    /// Creates a new reader wrapping the provided one and recording its reads
+    /// as a task with the provided name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Read;
+    ///
+    /// use chrono::TimeDelta;
+    /// use time_requirements::{clock::MockClock, prelude::*, timed_io::TimedReader};
+    ///
+    /// let clock = MockClock::default();
+    /// let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    /// {
+    ///     let mut reader = TimedReader::new(&[0_u8; 1024][..], "read", &mut tracker);
+    ///     // The time spent outside of the reads is not I/O time.
+    ///     clock.advance(TimeDelta::seconds(5));
+    ///     reader.read_to_end(&mut Vec::new()).unwrap();
+    /// }
+    ///
+    /// let task = tracker.get("read").unwrap();
+    /// assert_eq!(task.time(), TimeDelta::seconds(5));
+    /// assert_eq!(task.io_time(), Some(TimeDelta::zero()));
+    /// assert_eq!(task.processed_bytes(), Some(1024));
+    /// ```
+    pub fn new<S: ToString + ?Sized>(inner: R, name: &S, tracker: &'a mut TimeTracker) -> Self {
+        Self { inner, timer: IoTimer::new(name, tracker) }
+    }
+}
+
+impl<R> TimedReader<'_, R> {
+    #[must_use]
+    /// Returns the time spent inside the reads so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{prelude::*, timed_io::TimedReader};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let reader = TimedReader::new(std::io::empty(), "read", &mut tracker);
+    /// assert_eq!(reader.io_time(), chrono::TimeDelta::zero());
+    /// ```
+    pub fn io_time(&self) -> TimeDelta {
+        self.timer.time
+    }
+
+    #[must_use]
+    /// Returns the bytes read so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Read;
+    ///
+    /// use time_requirements::{prelude::*, timed_io::TimedReader};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut reader = TimedReader::new(&b"abcdef"[..], "read", &mut tracker);
+    /// reader.read_exact(&mut [0; 4]).unwrap();
+    /// assert_eq!(reader.processed_bytes(), 4);
+    /// ```
+    pub fn processed_bytes(&self) -> u64 {
+        self.timer.bytes
+    }
+
+    /// Returns a reference to the wrapped reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{prelude::*, timed_io::TimedReader};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let reader = TimedReader::new(&b"abc"[..], "read", &mut tracker);
+    /// assert_eq!(reader.get_ref().len(), 3);
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped reader. Reading directly
+    /// from it bypasses the timing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{prelude::*, timed_io::TimedReader};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut reader = TimedReader::new(std::io::Cursor::new(b"abc"), "read", &mut tracker);
+    /// reader.get_mut().set_position(1);
+    /// assert_eq!(reader.get_ref().position(), 1);
+    /// ```
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Records the task into the tracker and returns the wrapped reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Read;
+    ///
+    /// use time_requirements::{prelude::*, timed_io::TimedReader};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut reader = TimedReader::new(&b"header\nbody"[..], "header", &mut tracker);
+    /// reader.read_exact(&mut [0; 7]).unwrap();
+    /// let rest = reader.finish();
+    ///
+    /// assert_eq!(rest, b"body");
+    /// assert_eq!(tracker.get("header").unwrap().processed_bytes(), Some(7));
+    /// ```
+    pub fn finish(self) -> R {
+        let Self { inner, timer } = self;
+        drop(timer);
+        inner
+    }
+}
+
+impl<R: Read> Read for TimedReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let inner = &mut self.inner;
+        self.timer.measure(|| inner.read(buf))
+    }
+}
+
+/// A writer recording the time spent inside its `write` and `flush` calls and
+/// the bytes written as a task, when finished or dropped.
+///
+/// The task runs from the creation of the writer until it is finished or
+/// dropped, and records the time spent inside the writes and flushes as its
+/// [I/O time](crate::task::CompletedTask::io_time), so that the report shows
+/// how much of a step is spent writing as opposed to producing what is
+/// written, next to the bytes written. As with
+/// [`add_completed_task`](TimeTracker::add_completed_task), a slash-separated
+/// name records the task under the named sub-trackers.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+///
+/// use time_requirements::{prelude::*, timed_io::TimedWriter};
+///
+/// let mut tracker = TimeTracker::new("Project");
+/// let mut writer = TimedWriter::new(Vec::new(), "export/write", &mut tracker);
+/// for mass in [18.01, 44.01] {
+///     writeln!(writer, "{mass}").unwrap();
+/// }
+/// let output = writer.finish();
+///
+/// assert_eq!(output, b"18.01\n44.01\n");
+/// assert_eq!(tracker.get("export/write").unwrap().processed_bytes(), Some(12));
+/// ```
+pub struct TimedWriter<'a, W> {
+    /// The wrapped writer.
+    inner: W,
+    /// The timer accumulating the time and bytes of the writes.
+    timer: IoTimer<'a>,
+}
+
+impl<'a, W: Write> TimedWriter<'a, W> {
+    /// Creates a new writer wrapping the provided one and recording its
+    /// writes as a task with the provided name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use time_requirements::{prelude::*, timed_io::TimedWriter};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// {
+    ///     let mut writer = TimedWriter::new(std::io::sink(), "write", &mut tracker);
+    ///     writer.write_all(&[0; 512]).unwrap();
+    ///     writer.flush().unwrap();
+    /// }
+    /// assert_eq!(tracker.get("write").unwrap().processed_bytes(), Some(512));
+    /// ```
+    pub fn new<S: ToString + ?Sized>(inner: W, name: &S, tracker: &'a mut TimeTracker) -> Self {
+        Self { inner, timer: IoTimer::new(name, tracker) }
+    }
+}
+
+impl<W> TimedWriter<'_, W> {
+    #[must_use]
+    /// Returns the time spent inside the writes so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{prelude::*, timed_io::TimedWriter};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let writer = TimedWriter::new(std::io::sink(), "write", &mut tracker);
+    /// assert_eq!(writer.io_time(), chrono::TimeDelta::zero());
+    /// ```
+    pub fn io_time(&self) -> TimeDelta {
+        self.timer.time
+    }
+
+    #[must_use]
+    /// Returns the bytes written so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use time_requirements::{prelude::*, timed_io::TimedWriter};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut writer = TimedWriter::new(std::io::sink(), "write", &mut tracker);
+    /// writer.write_all(b"abc").unwrap();
+    /// assert_eq!(writer.processed_bytes(), 3);
+    /// ```
+    pub fn processed_bytes(&self) -> u64 {
+        self.timer.bytes
+    }
+
+    /// Returns a reference to the wrapped writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use time_requirements::{prelude::*, timed_io::TimedWriter};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut writer = TimedWriter::new(Vec::new(), "write", &mut tracker);
+    /// writer.write_all(b"abc").unwrap();
+    /// assert_eq!(writer.get_ref(), b"abc");
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped writer. Writing directly
+    /// to it bypasses the timing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time_requirements::{prelude::*, timed_io::TimedWriter};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut writer = TimedWriter::new(Vec::new(), "write", &mut tracker);
+    /// writer.get_mut().reserve(1024);
+    /// assert!(writer.get_ref().capacity() >= 1024);
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Records the task into the tracker and returns the wrapped writer,
+    /// without flushing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{BufWriter, Write};
+    ///
+    /// use time_requirements::{prelude::*, timed_io::TimedWriter};
+    ///
+    /// let mut tracker = TimeTracker::new("Project");
+    /// let mut writer = TimedWriter::new(BufWriter::new(Vec::new()), "write", &mut tracker);
+    /// writer.write_all(b"buffered").unwrap();
+    /// writer.flush().unwrap();
+    /// let output = writer.finish().into_inner().unwrap();
+    ///
+    /// assert_eq!(output, b"buffered");
+    /// assert_eq!(tracker.get("write").unwrap().processed_bytes(), Some(8));
+    /// ```
+    pub fn finish(self) -> W {
+        let Self { inner, timer } = self;
+        drop(timer);
+        inner
+    }
+}
+
+impl<W: Write> Write for TimedWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let inner = &mut self.inner;
+        self.timer.measure(|| inner.write(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let inner = &mut self.inner;
+        self.timer.measure_unit(|| inner.flush())
+    }
+}
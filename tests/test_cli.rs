@@ -0,0 +1,102 @@
+//! Submodule testing the command line interface timing shell commands.
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use time_requirements::prelude::*;
+
+/// Runs the command line interface with the provided arguments.
+fn time_requirements(arguments: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_time-requirements"))
+        .args(arguments)
+        .output()
+        .expect("the binary should run")
+}
+
+/// Creates an empty directory for the provided test, unique to the running
+/// process, so that concurrent test runs do not share their tracker files.
+fn test_directory(test: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("{test}_{}", std::process::id()));
+    std::fs::remove_dir_all(&directory).ok();
+    std::fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+/// Returns the path of the lock file of the provided tracker file, named by
+/// appending `.lock` to its file name.
+fn lock_path(tracker: &Path) -> PathBuf {
+    let mut path = tracker.as_os_str().to_owned();
+    path.push(".lock");
+    path.into()
+}
+
+#[test]
+#[cfg(unix)]
+/// Test that the runs of commands are appended to the tracker file.
+pub fn test_cli_run() {
+    let directory = test_directory("test_cli_run");
+    let path = directory.join("test_cli_run.json");
+    let tracker = path.to_str().unwrap();
+
+    let output = time_requirements(&["run", "--tracker", tracker, "--name", "fetch", "--", "true"]);
+    assert!(output.status.success());
+    let output = time_requirements(&["run", "--tracker", tracker, "--", "/bin/sh", "-c", "true"]);
+    assert!(output.status.success());
+    let output = time_requirements(&[
+        "run",
+        "--tracker",
+        tracker,
+        "--name",
+        "build/compile",
+        "--",
+        "sh",
+        "-c",
+        "exit 3",
+    ]);
+    assert_eq!(output.status.code(), Some(3));
+
+    let saved: TimeTracker =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(saved.name(), "test_cli_run");
+    assert!(saved.get("fetch").unwrap().outcome().is_success());
+    assert!(saved.get("sh").is_some());
+    let compile = saved.get("build/compile").unwrap();
+    assert!(compile.outcome().is_failure());
+    assert_eq!(compile.metadata().attribute("exit_code"), Some("3"));
+    assert!(lock_path(&path).exists());
+    assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 2);
+    std::fs::remove_dir_all(directory).ok();
+}
+
+#[test]
+#[cfg(unix)]
+/// Test that a tracker file which cannot be read is reported without
+/// preventing the command from running or changing its exit code.
+pub fn test_cli_invalid_tracker() {
+    let directory = test_directory("test_cli_invalid_tracker");
+    let path = directory.join("test_cli_invalid_tracker.json");
+    std::fs::write(&path, "not a tracker").unwrap();
+    let tracker = path.to_str().unwrap();
+
+    let output =
+        time_requirements(&["run", "--tracker", tracker, "--", "sh", "-c", "echo ran; exit 4"]);
+    assert_eq!(output.status.code(), Some(4));
+    assert_eq!(output.stdout, b"ran\n");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("invalid tracker"));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "not a tracker");
+    assert!(lock_path(&path).exists());
+    std::fs::remove_dir_all(directory).ok();
+}
+
+#[test]
+#[cfg(unix)]
+/// Test that invalid arguments are reported with the usage.
+pub fn test_cli_usage() {
+    let output = time_requirements(&["run", "--name", "step", "--", "true"]);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("--tracker is required"));
+    assert!(stderr.contains("Usage: time-requirements run"));
+}
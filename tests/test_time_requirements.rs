@@ -1,20 +1,26 @@
 //! Submodule defining the task tracker.
-use time_requirements::prelude::*;
+use chrono::TimeDelta;
+use time_requirements::{
+    clock::MockClock,
+    outcome::Outcome,
+    prelude::*,
+    report::{JsonRenderer, MarkdownRenderer, ReportModel, TreeRenderer},
+};
 
-/// Generate a time tracker for testing.
+/// Generate a time tracker for testing, driven by the provided clock.
 #[must_use]
-pub fn time_tracker() -> TimeTracker {
-    let mut tracker = TimeTracker::new("Test Project");
+pub fn time_tracker(clock: &MockClock) -> TimeTracker {
+    let mut tracker = TimeTracker::with_clock("Test Project", clock.clone());
 
-    let task1 = Task::new("Test Task 1");
-    // We sleep for 1 second to simulate the task taking time.
-    std::thread::sleep(std::time::Duration::from_secs(1));
+    let task1 = tracker.start_task("Test Task 1");
+    // We advance the clock by 1 second to simulate the task taking time.
+    clock.advance(TimeDelta::seconds(1));
     tracker.add_completed_task(task1);
 
     // We add a second task
-    let task2 = Task::new("Test Task 2");
-    // We sleep for 2 seconds to simulate the task taking time.
-    std::thread::sleep(std::time::Duration::from_secs(2));
+    let task2 = tracker.start_task("Test Task 2");
+    // We advance the clock by 2 seconds to simulate the task taking time.
+    clock.advance(TimeDelta::seconds(2));
     tracker.add_completed_task(task2);
 
     tracker
@@ -23,22 +29,22 @@ pub fn time_tracker() -> TimeTracker {
 #[test]
 /// Test the time requirements.
 pub fn test_time_requirements() {
-    let tracker = time_tracker();
+    let tracker = time_tracker(&MockClock::default());
 
     assert_eq!(tracker.name(), "Test Project");
     assert_eq!(tracker.tasks().count(), 2);
 
     let task1 = tracker.tasks().next().unwrap();
     assert_eq!(task1.name(), "Test Task 1");
-    assert_eq!(task1.time().num_seconds(), 1);
+    assert_eq!(task1.time(), TimeDelta::seconds(1));
 
     let task2 = tracker.tasks().nth(1).unwrap();
     assert_eq!(task2.name(), "Test Task 2");
-    assert_eq!(task2.time().num_seconds(), 2);
+    assert_eq!(task2.time(), TimeDelta::seconds(2));
 
-    assert_eq!(tracker.total_time().num_seconds(), 3);
+    assert_eq!(tracker.total_time(), TimeDelta::seconds(3));
     assert_eq!(tracker.slowest_task().unwrap().name(), "Test Task 2");
-    assert_eq!(tracker.slowest_task().unwrap().time().num_seconds(), 2);
+    assert_eq!(tracker.slowest_task().unwrap().time(), TimeDelta::seconds(2));
 }
 
 #[test]
@@ -46,9 +52,313 @@ pub fn test_time_requirements() {
 pub fn test_time_report() {
     use std::path::Path;
 
-    let tracker = time_tracker();
+    let tracker = time_tracker(&MockClock::default());
     tracker.write(Path::new("time_report.md")).unwrap();
 
     // Clean up the generated report
     std::fs::remove_file("time_report.md").unwrap();
 }
+
+#[test]
+/// Test that reports driven by a mock clock are reproducible exactly.
+pub fn test_time_report_snapshot() {
+    let clock = MockClock::default();
+    let mut tracker = time_tracker(&clock);
+    let mut sub_tracker = TimeTracker::with_clock("Sub Project", clock.clone());
+    let task = sub_tracker.start_task("Sub Task");
+    clock.advance(TimeDelta::milliseconds(500));
+    sub_tracker.add_completed_task(task);
+    tracker.extend(sub_tracker);
+    let report = Report::from(tracker);
+
+    assert_eq!(
+        report.render(&TreeRenderer { colored: false }),
+        "Test Project  3.50 s\n\
+         ├── Test Task 1  1.00 s  ██████░░░░░░░░░░░░░░ 28.57%\n\
+         ├── Test Task 2  2.00 s  ███████████░░░░░░░░░ 57.14%\n\
+         └── Sub Project  500.00 ms  ███░░░░░░░░░░░░░░░░░ 14.29%\n    \
+         └── Sub Task  500.00 ms  ████████████████████ 100.00%\n"
+    );
+
+    let markdown = report.render(&MarkdownRenderer);
+    assert!(markdown.contains(
+        "| Test Task 1 | 1 second  | 1 second  | 28.57%     | 28.57%             |\n\
+         | Test Task 2 | 2 seconds | 2 seconds | 57.14%     | 57.14%             |\n\
+         | Sub Project | 500 ms    | 500 ms    | 14.29%     | 14.29%             |\n"
+    ));
+    assert!(markdown.contains("The slowest task was `Test Task 2` which took 2 seconds"));
+
+    let json: serde_json::Value = serde_json::from_str(&report.render(&JsonRenderer)).unwrap();
+    assert_eq!(json["total_time"], 3_500_000_000_i64);
+    assert_eq!(json["start"], "1970-01-01T00:00:00");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+/// Test that the CPU time measured by a task is saved and reported next to
+/// its wall time.
+pub fn test_cpu_time_report() {
+    let mut tracker = TimeTracker::new("Test Project");
+    let task = tracker.start_task("Busy Task").with_cpu_time();
+    let sum: u64 = (0..10_000_000).map(std::hint::black_box).sum();
+    assert!(sum > 0);
+    tracker.add_completed_task(task);
+    tracker.add_completed_task("Idle Task");
+
+    let cpu = tracker.get("Busy Task").unwrap().cpu_time().expect("CPU time is measured on Linux");
+    assert!(cpu.thread() > TimeDelta::zero());
+    assert!(cpu.process() > TimeDelta::zero());
+    assert!(tracker.get("Idle Task").unwrap().cpu_time().is_none());
+
+    let json = serde_json::to_string(&tracker).unwrap();
+    let loaded: TimeTracker = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.get("Busy Task").unwrap().cpu_time(), Some(cpu));
+
+    let tree = Report::from(tracker).render(&TreeRenderer { colored: false });
+    let busy_line = tree.lines().find(|line| line.contains("Busy Task")).unwrap();
+    assert!(busy_line.contains("  cpu "));
+    let idle_line = tree.lines().find(|line| line.contains("Idle Task")).unwrap();
+    assert!(!idle_line.contains("  cpu "));
+}
+
+#[test]
+#[cfg(not(target_os = "linux"))]
+/// Test that the CPU time is not reported where it cannot be measured.
+pub fn test_cpu_time_unsupported() {
+    let mut tracker = TimeTracker::new("Test Project");
+    let task = tracker.start_task("Busy Task").with_cpu_time();
+    tracker.add_completed_task(task);
+    assert!(tracker.get("Busy Task").unwrap().cpu_time().is_none());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+/// Test that the memory usage measured by a task is saved and reported, so
+/// that the step blowing up memory stands out.
+pub fn test_memory_usage_report() {
+    let mut tracker = TimeTracker::new("Test Project");
+    let task = tracker.start_task("Hungry Task").with_memory_usage();
+    let buffer = std::hint::black_box(vec![1_u8; 64 * 1024 * 1024]);
+    tracker.add_completed_task(task);
+    drop(buffer);
+    tracker.add_completed_task("Frugal Task");
+
+    let memory = tracker
+        .get("Hungry Task")
+        .unwrap()
+        .memory_usage()
+        .expect("the memory usage is measured on Linux");
+    assert!(memory.peak_increase().unwrap() >= 32 * 1024 * 1024);
+    assert!(memory.peak_exact);
+    assert!(tracker.get("Frugal Task").unwrap().memory_usage().is_none());
+
+    let json = serde_json::to_string(&tracker).unwrap();
+    let loaded: TimeTracker = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.get("Hungry Task").unwrap().memory_usage(), Some(memory));
+
+    let report = Report::from(tracker).with_options(ReportOptions::default().with_columns([
+        Column::Name,
+        Column::StartMemory,
+        Column::EndMemory,
+        Column::PeakMemory,
+        Column::MemoryDelta,
+    ]));
+    let markdown = report.render(&MarkdownRenderer);
+    let hungry_row = markdown.lines().find(|line| line.starts_with("| Hungry Task")).unwrap();
+    assert!(hungry_row.contains(" MiB"));
+    assert!(hungry_row.contains('+'));
+
+    let tree = report.render(&TreeRenderer { colored: false });
+    let hungry_line = tree.lines().find(|line| line.contains("Hungry Task")).unwrap();
+    assert!(hungry_line.contains("  peak "));
+}
+
+#[test]
+#[cfg(not(target_os = "linux"))]
+/// Test that the memory usage is not reported where it cannot be measured.
+pub fn test_memory_usage_unsupported() {
+    let mut tracker = TimeTracker::new("Test Project");
+    let task = tracker.start_task("Hungry Task").with_memory_usage();
+    tracker.add_completed_task(task);
+    assert!(tracker.get("Hungry Task").unwrap().memory_usage().is_none());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+/// Test that the I/O counters of a task count the bytes it wrote.
+pub fn test_io_counters() {
+    let path = std::env::temp_dir().join(format!("test_io_counters_{}.bin", std::process::id()));
+    let task = Task::new("Write Task").with_io_counters();
+    std::fs::write(&path, vec![0_u8; 1024 * 1024]).unwrap();
+    let completed = task.complete();
+    std::fs::remove_file(&path).ok();
+
+    let io = completed.io_counters().expect("the I/O counters are measured on Linux");
+    assert!(io.written_bytes >= 1024 * 1024);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+/// Test that the scheduling events of a task count the voluntary context
+/// switches of the sleeps it made.
+pub fn test_scheduling_events() {
+    let task = Task::new("Sleeping Task").with_scheduling_events();
+    for _ in 0..10 {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    let completed = task.complete();
+
+    let events = completed.scheduling_events().expect("the scheduling events are counted on Linux");
+    assert!(events.voluntary_context_switches >= 10);
+}
+
+#[test]
+/// Test that the items and bytes processed by tasks normalize their time in
+/// the report.
+pub fn test_throughput_report() {
+    let clock = MockClock::default();
+    let mut tracker = TimeTracker::with_clock("Test Project", clock.clone());
+    let mut task = tracker.start_task("Annotate");
+    task.add_items(1_000);
+    clock.advance(TimeDelta::seconds(2));
+    tracker.add_completed_task(task);
+    let mut task = tracker.start_task("Download");
+    task.add_bytes(8 * 1024 * 1024);
+    clock.advance(TimeDelta::seconds(4));
+    tracker.add_completed_task(task);
+
+    let annotate = tracker.get("Annotate").unwrap();
+    assert_eq!(annotate.items_per_second(), Some(500.0));
+    assert_eq!(annotate.time_per_item(), Some(TimeDelta::milliseconds(2)));
+    assert_eq!(tracker.get("Download").unwrap().bytes_per_second(), Some(2.0 * 1024.0 * 1024.0));
+
+    let report = Report::from(tracker).with_options(ReportOptions::default().with_columns([
+        Column::Name,
+        Column::Items,
+        Column::ItemsPerSecond,
+        Column::TimePerItem,
+        Column::ProcessedBytes,
+        Column::BytesPerSecond,
+    ]));
+    let markdown = report.render(&MarkdownRenderer);
+    assert!(markdown.contains(
+        "| Annotate | 1000  | 500.00  | 2.00 ms       |           |             |\n\
+         | Download |       |         |               | 8.00 MiB  | 2.00 MiB/s  |"
+    ));
+    assert_eq!(
+        report.render(&TreeRenderer { colored: false }),
+        "Test Project  6.00 s\n\
+         ├── Annotate  2.00 s  ███████░░░░░░░░░░░░░ 33.33%  1000 items (500.00/s)\n\
+         └── Download  4.00 s  █████████████░░░░░░░ 66.67%  8.00 MiB (2.00 MiB/s)\n"
+    );
+}
+
+#[test]
+/// Test that the tasks completed with their items or bytes keep them and
+/// their time once added to a tracker, also with an outcome.
+pub fn test_completed_with_throughput() {
+    let clock = MockClock::default();
+    let mut tracker = TimeTracker::with_clock("Test Project", clock.clone());
+    let task = tracker.start_task("Annotate");
+    clock.advance(TimeDelta::seconds(2));
+    tracker.add_completed_task(task.complete_with_items(1_000));
+    let task = tracker.start_task("Download");
+    clock.advance(TimeDelta::seconds(4));
+    tracker.add_completed_task_with(
+        task.complete_with_bytes(8 * 1024 * 1024),
+        Outcome::failure("Truncated"),
+    );
+
+    let annotate = tracker.get("Annotate").unwrap();
+    assert_eq!(annotate.time(), TimeDelta::seconds(2));
+    assert_eq!(annotate.items(), Some(1_000));
+    assert_eq!(annotate.time_per_item(), Some(TimeDelta::milliseconds(2)));
+
+    let download = tracker.get("Download").unwrap();
+    assert_eq!(download.time(), TimeDelta::seconds(4));
+    assert_eq!(download.bytes_per_second(), Some(2.0 * 1024.0 * 1024.0));
+    assert_eq!(download.outcome().status(), "failure");
+}
+
+#[test]
+/// Test that the tasks added to a tracker are completed on the clock they
+/// were started with, even when it is not the clock of the tracker.
+pub fn test_tasks_complete_on_their_own_clock() {
+    let clock = MockClock::default();
+    let mut tracker = TimeTracker::with_clock("Mock Project", clock.clone());
+    let task = Task::new("System Task");
+    clock.advance(TimeDelta::seconds(5));
+    tracker.add_completed_task(task);
+    let time = tracker.get("System Task").unwrap().time();
+    assert!(time >= TimeDelta::zero() && time < TimeDelta::seconds(5));
+
+    let mut tracker = TimeTracker::new("System Project");
+    let task = Task::with_clock("Mock Task", &clock);
+    clock.advance(TimeDelta::seconds(2));
+    tracker.add_completed_task(task);
+    assert_eq!(tracker.get("Mock Task").unwrap().time(), TimeDelta::seconds(2));
+
+    let task = Task::with_clock("Mock Task", &clock);
+    clock.advance(TimeDelta::seconds(3));
+    tracker.add_or_extend_completed_task(task);
+    assert_eq!(tracker.get("Mock Task").unwrap().time(), TimeDelta::seconds(5));
+}
+
+#[test]
+/// Test that the percentages of the rows of a finished tracker add up with
+/// the untracked time to the whole elapsed time.
+pub fn test_untracked_percentages() {
+    let clock = MockClock::default();
+    let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    let task = tracker.start_task("Task");
+    clock.advance(TimeDelta::seconds(1));
+    tracker.add_completed_task(task);
+    clock.advance(TimeDelta::seconds(9));
+    tracker.finish();
+
+    let model = ReportModel::new(&tracker, &ReportOptions::default());
+    let untracked = model.untracked.as_ref().unwrap();
+    assert_eq!(untracked.time, TimeDelta::seconds(9));
+    assert!((untracked.percentage - 90.0).abs() < 1e-9);
+    assert!((untracked.root_percentage - 90.0).abs() < 1e-9);
+
+    let rows = model.rows.iter().chain(&model.untracked);
+    let percentage: f64 = rows.clone().map(|row| row.percentage).sum();
+    let root_percentage: f64 = rows.map(|row| row.root_percentage).sum();
+    assert!((percentage - 100.0).abs() < 1e-9);
+    assert!((root_percentage - 100.0).abs() < 1e-9);
+}
+
+#[test]
+/// Test that a sub-tracker whose tasks are interleaved with other tasks spans
+/// them, without counting the gaps as overlapping the other tasks.
+pub fn test_interleaved_sub_tracker() {
+    let clock = MockClock::default();
+    let mut tracker = TimeTracker::with_clock("Project", clock.clone());
+    for (name, seconds) in [("build/a", 1), ("x", 4), ("build/b", 1)] {
+        let task = tracker.start_task(name);
+        clock.advance(TimeDelta::seconds(seconds));
+        tracker.add_completed_task(task);
+    }
+    tracker.finish();
+
+    let build = tracker.get("build").unwrap();
+    assert_eq!(build.start(), tracker.start());
+    assert_eq!(build.end(), tracker.start() + TimeDelta::seconds(6));
+    assert_eq!(build.time(), TimeDelta::seconds(2));
+
+    assert_eq!(tracker.tracked_time(), TimeDelta::seconds(6));
+    assert_eq!(tracker.untracked_time(), TimeDelta::zero());
+    assert!((tracker.parallelism() - 1.0).abs() < f64::EPSILON);
+    assert!(tracker.tasks().all(|task| tracker.concurrency_of(task) == 1));
+
+    let model = ReportModel::new(&tracker, &ReportOptions::default());
+    assert!(model.untracked.is_none());
+    assert!(model.rows.iter().all(|row| row.concurrency == 1));
+
+    // The busy time of the summary survives a round trip through JSON.
+    let saved: TimeTracker =
+        serde_json::from_str(&serde_json::to_string(&tracker).unwrap()).unwrap();
+    assert_eq!(saved.get("build").unwrap().time(), TimeDelta::seconds(2));
+    assert_eq!(saved.get("build").unwrap().end(), build.end());
+}